@@ -0,0 +1,266 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, LitStr, Path};
+
+/// Implements `mdtable::MdRow` and `From<Self> for mdtable::Row` for a struct
+/// with named fields.
+///
+/// Field attributes, all inside `#[mdtable(...)]`:
+/// - `rename = "Title"` sets the column title, which defaults to the field name.
+/// - `align = "left"` (or `"none"`, `"center"`, `"right"`, `"decimal"`) sets
+///   the column alignment.
+/// - `skip` leaves the field out of the table.
+/// - `header` makes the field the row header instead of the first field.
+/// - `format = "path::to::fn"` formats the field with a `fn(&T) -> String`
+///   instead of `Display`.
+#[proc_macro_derive(MdRow, attributes(mdtable))]
+pub fn derive_md_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+struct Column {
+    ident: Ident,
+    title: String,
+    align: Option<TokenStream2>,
+    format: Option<Path>,
+    header: bool,
+}
+
+impl Column {
+    fn parse(field: &syn::Field) -> syn::Result<Option<Self>> {
+        let ident = field.ident.clone().expect("named field");
+        let mut column = Column {
+            title: ident.to_string(),
+            ident,
+            align: None,
+            format: None,
+            header: false,
+        };
+        let mut skip = false;
+
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("mdtable")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    column.title = meta.value()?.parse::<LitStr>()?.value();
+                } else if meta.path.is_ident("align") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    column.align = Some(match lit.value().as_str() {
+                        "none" => quote!(::mdtable::Alignment::None),
+                        "left" => quote!(::mdtable::Alignment::Left),
+                        "center" => quote!(::mdtable::Alignment::Center),
+                        "right" => quote!(::mdtable::Alignment::Right),
+                        "decimal" => quote!(::mdtable::Alignment::Decimal),
+                        _ => {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "expected `none`, `left`, `center`, `right` or `decimal`",
+                            ))
+                        }
+                    });
+                } else if meta.path.is_ident("format") {
+                    column.format = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                } else if meta.path.is_ident("skip") {
+                    skip = true;
+                } else if meta.path.is_ident("header") {
+                    column.header = true;
+                } else {
+                    return Err(meta.error("unknown mdtable attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok((!skip).then_some(column))
+    }
+
+    fn cell(&self) -> TokenStream2 {
+        let ident = &self.ident;
+        match &self.format {
+            Some(format) => quote!(#format(&value.#ident)),
+            None => quote!(::std::string::ToString::to_string(&value.#ident)),
+        }
+    }
+
+    fn title(&self) -> TokenStream2 {
+        let title = &self.title;
+        quote!(::std::string::String::from(#title))
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "MdRow needs a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "MdRow can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut columns = Vec::new();
+    for field in fields {
+        columns.extend(Column::parse(field)?);
+    }
+
+    let header = match columns.iter().filter(|c| c.header).count() {
+        0 if columns.is_empty() => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "MdRow needs at least one field that isn't skipped",
+            ))
+        }
+        0 => 0,
+        1 => columns.iter().position(|c| c.header).unwrap(),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "only one field can be the row header",
+            ))
+        }
+    };
+    let header = columns.remove(header);
+    let width = columns.len();
+
+    let header_title = header.title();
+    let titles = columns.iter().map(Column::title);
+    let header_align = header
+        .align
+        .clone()
+        .unwrap_or_else(|| quote!(::mdtable::Alignment::None));
+    let aligns = columns.iter().map(|c| {
+        c.align
+            .clone()
+            .unwrap_or_else(|| quote!(::mdtable::Alignment::Right))
+    });
+    let header_cell = header.cell();
+    let cells = columns.iter().map(Column::cell);
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::mdtable::MdRow<#width> for #name #ty_generics #where_clause {
+            fn header() -> ::mdtable::Row<::std::string::String, ::std::string::String, #width> {
+                ::mdtable::Row::from((#header_title, [#(#titles),*]))
+            }
+
+            fn alignments() -> ::mdtable::Row<::mdtable::Alignment, ::mdtable::Alignment, #width> {
+                ::mdtable::Row::from((#header_align, [#(#aligns),*]))
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<#name #ty_generics>
+            for ::mdtable::Row<::std::string::String, ::std::string::String, #width>
+            #where_clause
+        {
+            fn from(value: #name #ty_generics) -> Self {
+                ::mdtable::Row::from((#header_cell, [#(#cells),*]))
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::expand;
+
+    fn error(input: syn::DeriveInput) -> String {
+        expand(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn rejects_unknown_attributes() {
+        let message = error(parse_quote! {
+            struct S {
+                #[mdtable(colour = "red")]
+                a: u32,
+            }
+        });
+        assert_eq!(message, "unknown mdtable attribute");
+    }
+
+    #[test]
+    fn rejects_unknown_alignments() {
+        let message = error(parse_quote! {
+            struct S {
+                #[mdtable(align = "middle")]
+                a: u32,
+            }
+        });
+        assert_eq!(
+            message,
+            "expected `none`, `left`, `center`, `right` or `decimal`"
+        );
+    }
+
+    #[test]
+    fn rejects_two_header_fields() {
+        let message = error(parse_quote! {
+            struct S {
+                #[mdtable(header)]
+                a: u32,
+                #[mdtable(header)]
+                b: u32,
+            }
+        });
+        assert_eq!(message, "only one field can be the row header");
+    }
+
+    #[test]
+    fn rejects_structs_without_columns() {
+        let message = error(parse_quote! {
+            struct S {
+                #[mdtable(skip)]
+                a: u32,
+            }
+        });
+        assert_eq!(message, "MdRow needs at least one field that isn't skipped");
+    }
+
+    #[test]
+    fn rejects_tuple_structs_and_enums() {
+        let message = error(parse_quote! {
+            struct S(u32, u32);
+        });
+        assert_eq!(message, "MdRow needs a struct with named fields");
+
+        let message = error(parse_quote! {
+            enum E { A }
+        });
+        assert_eq!(message, "MdRow can only be derived for structs");
+    }
+
+    #[test]
+    fn counts_columns_after_the_header() {
+        let tokens = expand(parse_quote! {
+            struct S {
+                a: u32,
+                #[mdtable(skip)]
+                b: u32,
+                c: u32,
+            }
+        })
+        .unwrap()
+        .to_string();
+        assert!(
+            tokens.contains(":: mdtable :: MdRow < 1usize >"),
+            "{}",
+            tokens
+        );
+    }
+}
@@ -0,0 +1,338 @@
+/// Reads either an array of objects, whose keys become the header in order
+/// of first appearance, or an array of arrays whose first entry is the header.
+pub fn records(input: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut parser = Parser { input, pos: 0 };
+    let value = parser.value()?;
+    parser.whitespace();
+    if parser.pos != input.len() {
+        return Err(parser.error("trailing characters"));
+    }
+
+    let items = match value {
+        Value::Array(items) => items,
+        _ => return Err("expected an array of records".into()),
+    };
+
+    if items.iter().all(|item| matches!(item, Value::Array(_))) {
+        return Ok(items
+            .into_iter()
+            .map(|item| match item {
+                Value::Array(cells) => cells.into_iter().map(Value::into_cell).collect(),
+                _ => unreachable!(),
+            })
+            .collect());
+    }
+
+    let mut header: Vec<String> = Vec::new();
+    let mut rows = Vec::new();
+    for item in items {
+        let Value::Object(fields) = item else {
+            return Err("expected every record to be an object".into());
+        };
+
+        let mut row = vec![String::new(); header.len()];
+        for (key, value) in fields {
+            let column = match header.iter().position(|name| *name == key) {
+                Some(column) => column,
+                None => {
+                    header.push(key);
+                    row.push(String::new());
+                    header.len() - 1
+                }
+            };
+            row[column] = value.into_cell();
+        }
+        rows.push(row);
+    }
+
+    rows.insert(0, header);
+    Ok(rows)
+}
+
+enum Value {
+    String(String),
+    /// Numbers, booleans and `null`, kept as written.
+    Literal(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    fn into_cell(self) -> String {
+        match self {
+            Value::String(s) => s,
+            Value::Literal(s) if s == "null" => String::new(),
+            Value::Literal(s) => s,
+            nested => nested.to_json(),
+        }
+    }
+
+    fn to_json(&self) -> String {
+        match self {
+            Value::String(s) => escape(s),
+            Value::Literal(s) => s.clone(),
+            Value::Array(items) => {
+                let items: Vec<_> = items.iter().map(Value::to_json).collect();
+                format!("[{}]", items.join(","))
+            }
+            Value::Object(fields) => {
+                let fields: Vec<_> = fields
+                    .iter()
+                    .map(|(key, value)| format!("{}:{}", escape(key), value.to_json()))
+                    .collect();
+                format!("{{{}}}", fields.join(","))
+            }
+        }
+    }
+}
+
+/// Whether `s` follows JSON's number grammar, which is stricter than
+/// `f64::from_str`: no `+`, no leading zeros, no `Infinity` or `NaN`, and
+/// digits on both sides of a decimal point.
+fn is_number(s: &str) -> bool {
+    let digits = |s: &str| s.len() - s.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+
+    let s = s.strip_prefix('-').unwrap_or(s);
+    let integer = digits(s);
+    if integer == 0 || (integer > 1 && s.starts_with('0')) {
+        return false;
+    }
+
+    let mut rest = &s[integer..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let length = digits(fraction);
+        if length == 0 {
+            return false;
+        }
+        rest = &fraction[length..];
+    }
+    if let Some(exponent) = rest.strip_prefix(['e', 'E']) {
+        let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        let length = digits(exponent);
+        if length == 0 {
+            return false;
+        }
+        rest = &exponent[length..];
+    }
+    rest.is_empty()
+}
+
+/// Quotes `s` as a JSON string.
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c < ' ' => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+struct Parser<'i> {
+    input: &'i str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> String {
+        format!("invalid JSON at byte {}: {}", self.pos, message)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn whitespace(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.whitespace();
+        match self.bump() {
+            Some(c) if c == expected => Ok(()),
+            _ => Err(self.error(&format!("expected `{}`", expected))),
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.whitespace();
+        match self.peek() {
+            Some('"') => self.string().map(Value::String),
+            Some('[') => self.array(),
+            Some('{') => self.object(),
+            Some(_) => self.literal(),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn list<T>(
+        &mut self,
+        close: char,
+        mut item: impl FnMut(&mut Self) -> Result<T, String>,
+    ) -> Result<Vec<T>, String> {
+        self.bump();
+        let mut items = Vec::new();
+        self.whitespace();
+        if self.peek() == Some(close) {
+            self.bump();
+            return Ok(items);
+        }
+
+        loop {
+            items.push(item(self)?);
+            self.whitespace();
+            match self.bump() {
+                Some(',') => continue,
+                Some(c) if c == close => return Ok(items),
+                _ => return Err(self.error(&format!("expected `,` or `{}`", close))),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, String> {
+        self.list(']', Self::value).map(Value::Array)
+    }
+
+    fn object(&mut self) -> Result<Value, String> {
+        self.list('}', |parser| {
+            parser.whitespace();
+            let key = parser.string()?;
+            parser.expect(':')?;
+            Ok((key, parser.value()?))
+        })
+        .map(Value::Object)
+    }
+
+    fn literal(&mut self) -> Result<Value, String> {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_alphanumeric() || "+-.".contains(c)) {
+            self.pos += 1;
+        }
+
+        let literal = &self.input[start..self.pos];
+        if !matches!(literal, "true" | "false" | "null") && !is_number(literal) {
+            return Err(self.error("expected a value"));
+        }
+        Ok(Value::Literal(literal.into()))
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        if self.bump() != Some('"') {
+            return Err(self.error("expected a string"));
+        }
+
+        let mut s = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(s),
+                Some('\\') => match self.bump() {
+                    Some('n') => s.push('\n'),
+                    Some('t') => s.push('\t'),
+                    Some('r') => s.push('\r'),
+                    Some('b') => s.push('\u{8}'),
+                    Some('f') => s.push('\u{c}'),
+                    Some('u') => s.push(self.unicode_escape()?),
+                    Some(c @ ('"' | '\\' | '/')) => s.push(c),
+                    _ => return Err(self.error("invalid escape")),
+                },
+                Some(c) => s.push(c),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        let digits = self.input.get(self.pos..self.pos + 4);
+        let code = digits.and_then(|digits| u32::from_str_radix(digits, 16).ok());
+        let code = code.ok_or_else(|| self.error("invalid unicode escape"))?;
+        self.pos += 4;
+        Ok(code)
+    }
+
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        let high = self.hex4()?;
+        let code = if (0xD800..0xDC00).contains(&high) && self.input[self.pos..].starts_with("\\u")
+        {
+            self.pos += 2;
+            let low = self.hex4()?;
+            0x10000 + ((high - 0xD800) << 10) + (low.wrapping_sub(0xDC00) & 0x3FF)
+        } else {
+            high
+        };
+        char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::records;
+
+    #[test]
+    fn numbers_follow_json_grammar() {
+        for number in ["0", "-0", "10", "1.5", "-0.25e+10", "1E3", "2e-7"] {
+            let input = format!("[[\"n\"],[{}]]", number);
+            assert_eq!(records(&input).unwrap()[1], [number], "{}", number);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_numbers() {
+        for number in [
+            "01",
+            "+1",
+            "Infinity",
+            "-Infinity",
+            "NaN",
+            ".5",
+            "1.",
+            "1e",
+            "1e+",
+            "-",
+            "0x10",
+            "1.5.2",
+        ] {
+            let input = format!("[[\"n\"],[{}]]", number);
+            assert!(records(&input).is_err(), "{}", number);
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_documents() {
+        for input in [
+            "",
+            "{}",
+            "[1]",
+            "[[\"a\"]",
+            "[[\"a\"]] x",
+            "[[\"a\\q\"]]",
+            "[[\"a]]",
+            "[{\"a\" 1}]",
+            "[{\"a\": 1}, [2]]",
+            "[[tru]]",
+        ] {
+            assert!(records(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn nested_values_become_json() {
+        let rows = records(r#"[{"a": [1, "\u0001", "\"q\"\\"], "b": {"k\n": null}}]"#).unwrap();
+        assert_eq!(rows[1], [r#"[1,"\u0001","\"q\"\\"]"#, r#"{"k\n":null}"#]);
+    }
+}
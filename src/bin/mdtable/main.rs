@@ -0,0 +1,191 @@
+use std::{
+    fs,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use mdtable::{Alignment, DynBuilder, Style};
+
+mod json;
+
+const USAGE: &str = "\
+usage: mdtable fmt [FILE...]
+       mdtable check [FILE...]
+       mdtable convert [--from csv|tsv|json] [FILE]
+
+fmt      reformat every table in FILEs in place, or stdin to stdout
+check    exit with status 1 if any table in FILEs (or stdin) is not formatted
+convert  turn CSV, TSV or JSON records into a markdown table on stdout";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (command, args) = match args.split_first() {
+        Some((command, args)) => (command.as_str(), args),
+        None => return usage(),
+    };
+
+    let result = match command {
+        "fmt" => fmt(args),
+        "check" => check(args),
+        "convert" => convert(args),
+        "-h" | "--help" | "help" => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        _ => return usage(),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("mdtable: {}", error);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn usage() -> ExitCode {
+    eprintln!("{}", USAGE);
+    ExitCode::from(2)
+}
+
+fn fmt(files: &[String]) -> Result<ExitCode, String> {
+    if files.is_empty() {
+        let document = read_input(None)?;
+        write_stdout(&mdtable::reformat(&document))?;
+    }
+
+    for file in files {
+        let path = Path::new(file);
+        let document = read_input(Some(path))?;
+        let formatted = mdtable::reformat(&document);
+        if formatted != document {
+            fs::write(path, formatted).map_err(|e| format!("{}: {}", file, e))?;
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn check(files: &[String]) -> Result<ExitCode, String> {
+    let inputs: Vec<Option<&Path>> = if files.is_empty() {
+        vec![None]
+    } else {
+        files.iter().map(|file| Some(Path::new(file))).collect()
+    };
+
+    let mut clean = true;
+    for input in inputs {
+        let document = read_input(input)?;
+        if mdtable::reformat(&document) != document {
+            clean = false;
+            let name = input.map_or("<stdin>".into(), |path| path.display().to_string());
+            eprintln!("{}: tables are not formatted", name);
+        }
+    }
+
+    Ok(if clean {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+enum InputFormat {
+    Csv,
+    Tsv,
+    Json,
+}
+
+impl InputFormat {
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "tsv" | "tab" => Some(Self::Tsv),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+fn convert(args: &[String]) -> Result<ExitCode, String> {
+    let mut format = None;
+    let mut file = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--from" => {
+                let name = args.next().ok_or("--from needs a format")?;
+                format = Some(InputFormat::parse(name).ok_or(format!("unknown format {}", name))?);
+            }
+            _ if file.is_none() => file = Some(PathBuf::from(arg)),
+            _ => return Err(format!("unexpected argument {}", arg)),
+        }
+    }
+
+    let format = format
+        .or_else(|| {
+            let extension = file.as_deref()?.extension()?.to_str()?;
+            InputFormat::parse(extension)
+        })
+        .unwrap_or(InputFormat::Csv);
+
+    let input = read_input(file.as_deref())?;
+    let mut builder = match format {
+        InputFormat::Csv => DynBuilder::from_csv(&input).map_err(|e| e.to_string())?,
+        InputFormat::Tsv => DynBuilder::from_tsv(&input).map_err(|e| e.to_string())?,
+        InputFormat::Json => from_records(json::records(&input)?)?,
+    };
+
+    let width = builder.width().unwrap_or_default();
+    builder.style(Style::Github);
+    builder.alignments((Alignment::None, vec![Alignment::None; width]));
+
+    let table = builder.try_finish().map_err(|e| e.to_string())?;
+    write_stdout(&table.to_string())?;
+    Ok(ExitCode::SUCCESS)
+}
+
+fn from_records(records: Vec<Vec<String>>) -> Result<DynBuilder<String, String, String>, String> {
+    let columns = records.iter().map(Vec::len).max().unwrap_or(0);
+    if columns == 0 {
+        return Err("no records to convert".into());
+    }
+
+    let row = |mut cells: Vec<String>| {
+        cells.resize(columns, String::new());
+        let header = cells.remove(0);
+        (header, cells)
+    };
+
+    let mut records = records.into_iter();
+    let mut builder = DynBuilder::new();
+    builder.header(row(records.next().unwrap()));
+    for record in records {
+        builder.row(row(record));
+    }
+
+    Ok(builder)
+}
+
+fn read_input(path: Option<&Path>) -> Result<String, String> {
+    match path {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e)),
+        None => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("<stdin>: {}", e))?;
+            Ok(input)
+        }
+    }
+}
+
+fn write_stdout(output: &str) -> Result<(), String> {
+    io::stdout()
+        .write_all(output.as_bytes())
+        .map_err(|e| format!("<stdout>: {}", e))
+}
@@ -0,0 +1,178 @@
+use std::{fmt::Display, iter};
+
+use crate::{line_widths, lines, write_padded, View};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Border {
+    /// `+---+---+`
+    Ascii,
+    /// `┌───┬───┐`
+    #[default]
+    Single,
+    /// `╔═══╦═══╗`
+    Double,
+    /// `╭───┬───╮`
+    Rounded,
+    /// Single lines with a heavy `┝━━━┿━━━┥` rule under the header.
+    HeavyHeader,
+}
+
+/// Left, fill, crossing and right glyphs of a horizontal rule.
+type Rule = [char; 4];
+
+struct Glyphs {
+    top: Rule,
+    header: Rule,
+    bottom: Rule,
+    vertical: char,
+}
+
+impl Border {
+    fn glyphs(self) -> Glyphs {
+        const SINGLE: Glyphs = Glyphs {
+            top: ['┌', '─', '┬', '┐'],
+            header: ['├', '─', '┼', '┤'],
+            bottom: ['└', '─', '┴', '┘'],
+            vertical: '│',
+        };
+
+        match self {
+            Border::Ascii => Glyphs {
+                top: ['+', '-', '+', '+'],
+                header: ['+', '-', '+', '+'],
+                bottom: ['+', '-', '+', '+'],
+                vertical: '|',
+            },
+            Border::Single => SINGLE,
+            Border::Double => Glyphs {
+                top: ['╔', '═', '╦', '╗'],
+                header: ['╠', '═', '╬', '╣'],
+                bottom: ['╚', '═', '╩', '╝'],
+                vertical: '║',
+            },
+            Border::Rounded => Glyphs {
+                top: ['╭', '─', '┬', '╮'],
+                bottom: ['╰', '─', '┴', '╯'],
+                ..SINGLE
+            },
+            Border::HeavyHeader => Glyphs {
+                header: ['┝', '━', '┿', '┥'],
+                ..SINGLE
+            },
+        }
+    }
+}
+
+/// Renders a table with box-drawing borders for terminals, created by
+/// `Table::boxed`. Cells are written as they are, without markdown escaping,
+/// and cells with line breaks span several lines.
+pub struct Boxed<'t, LH, TH, T> {
+    view: View<'t, LH, TH, T>,
+    border: Border,
+}
+
+impl<'t, LH, TH, T> Boxed<'t, LH, TH, T> {
+    pub(crate) fn new(view: View<'t, LH, TH, T>, border: Border) -> Self {
+        Self { view, border }
+    }
+
+    fn rule(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        widths: &[usize],
+        rule: Rule,
+    ) -> std::fmt::Result {
+        let [left, fill, cross, right] = rule;
+
+        write!(f, "{}", left)?;
+        for (i, width) in widths.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", cross)?;
+            }
+            for _ in 0..width + 2 {
+                write!(f, "{}", fill)?;
+            }
+        }
+        writeln!(f, "{}", right)
+    }
+
+    fn row(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        widths: &[usize],
+        cells: &[Vec<&str>],
+    ) -> std::fmt::Result {
+        let vertical = self.border.glyphs().vertical;
+        let height = cells.iter().map(Vec::len).max().unwrap_or(1);
+
+        for line in 0..height {
+            for (i, cell) in cells.iter().enumerate() {
+                write!(f, "{} ", vertical)?;
+                let text = cell.get(line).copied().unwrap_or_default();
+                write_padded(f, text, widths[i], self.view.alignments[i])?;
+                write!(f, " ")?;
+            }
+            writeln!(f, "{}", vertical)?;
+        }
+        Ok(())
+    }
+}
+
+impl<LH, TH, T> Display for Boxed<'_, LH, TH, T>
+where
+    LH: Display,
+    TH: Display,
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let glyphs = self.border.glyphs();
+
+        let header: Vec<String> = self.view.header.texts().collect();
+        let rows: Vec<Vec<String>> = self
+            .view
+            .rows
+            .iter()
+            .map(|&row| self.view.padded(row).collect())
+            .collect();
+
+        let header: Vec<Vec<&str>> = header.iter().map(|text| lines(text)).collect();
+        let rows: Vec<Vec<Vec<&str>>> = rows
+            .iter()
+            .map(|row| row.iter().map(|text| lines(text)).collect())
+            .collect();
+
+        let widths =
+            line_widths(iter::once(header.as_slice()).chain(rows.iter().map(Vec::as_slice)));
+
+        self.rule(f, &widths, glyphs.top)?;
+        self.row(f, &widths, &header)?;
+        self.rule(f, &widths, glyphs.header)?;
+        for row in &rows {
+            self.row(f, &widths, row)?;
+        }
+        self.rule(f, &widths, glyphs.bottom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Border, Builder};
+
+    #[test]
+    fn boxed_cells_are_not_escaped() {
+        let mut builder: Builder<&str, &str, &str, 1> = Builder::new();
+        builder.header(("a", ["b"]));
+        builder.row(("x|y", ["1\n22"]));
+        let table = builder.finish();
+
+        assert_eq!(
+            table.boxed(Border::Ascii).to_string(),
+            "+-----+-----+\n\
+             | a   |   b |\n\
+             +-----+-----+\n\
+             | x|y |   1 |\n\
+             |     |  22 |\n\
+             +-----+-----+\n"
+        );
+    }
+}
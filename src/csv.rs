@@ -0,0 +1,247 @@
+use std::fmt::Display;
+
+use crate::{Builder, DynBuilder, DynRow, Error, ErrorKind, Row, View};
+
+/// Renders a table as RFC 4180 records, created by `Table::csv` and
+/// `Table::tsv`.
+pub struct Csv<'t, LH, TH, T> {
+    view: View<'t, LH, TH, T>,
+    delimiter: char,
+}
+
+impl<'t, LH, TH, T> Csv<'t, LH, TH, T> {
+    pub(crate) fn new(view: View<'t, LH, TH, T>, delimiter: char) -> Self {
+        Self { view, delimiter }
+    }
+
+    fn record(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        cells: impl Iterator<Item = String>,
+    ) -> std::fmt::Result {
+        for (i, cell) in cells.enumerate() {
+            if i > 0 {
+                write!(f, "{}", self.delimiter)?;
+            }
+
+            if cell.contains([self.delimiter, '"', '\r', '\n']) {
+                write!(f, "\"{}\"", cell.replace('"', "\"\""))?;
+            } else {
+                write!(f, "{}", cell)?;
+            }
+        }
+        write!(f, "\r\n")
+    }
+}
+
+impl<LH, TH, T> Display for Csv<'_, LH, TH, T>
+where
+    LH: Display,
+    TH: Display,
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.record(f, self.view.header.texts())?;
+        for &row in &self.view.rows {
+            self.record(f, row.formatted(self.view.formats))?;
+        }
+        Ok(())
+    }
+}
+
+impl<const WIDTH: usize> Builder<String, String, String, WIDTH> {
+    pub fn from_csv(input: &str) -> Result<Self, Error> {
+        Self::from_delimited(input, ',')
+    }
+
+    pub fn from_tsv(input: &str) -> Result<Self, Error> {
+        Self::from_delimited(input, '\t')
+    }
+
+    fn from_delimited(input: &str, delimiter: char) -> Result<Self, Error> {
+        let mut records = parse(input, delimiter)?.into_iter();
+        let mut builder = Self::new();
+
+        let (line, header) = records
+            .next()
+            .ok_or_else(|| Error::new(ErrorKind::MissingHeader, input))?;
+        builder.header(into_row(header, line, delimiter)?);
+        for (line, record) in records {
+            builder.row(into_row(record, line, delimiter)?);
+        }
+
+        Ok(builder)
+    }
+}
+
+impl DynBuilder<String, String, String> {
+    pub fn from_csv(input: &str) -> Result<Self, Error> {
+        Self::from_delimited(input, ',')
+    }
+
+    pub fn from_tsv(input: &str) -> Result<Self, Error> {
+        Self::from_delimited(input, '\t')
+    }
+
+    fn from_delimited(input: &str, delimiter: char) -> Result<Self, Error> {
+        let mut records = parse(input, delimiter)?.into_iter();
+        let mut builder = Self::new();
+
+        let (_, header) = records
+            .next()
+            .ok_or_else(|| Error::new(ErrorKind::MissingHeader, input))?;
+        let width = header.len();
+        builder.header(into_dyn_row(header));
+        for (line, record) in records {
+            if record.len() != width {
+                return Err(column_count(width, record, line, delimiter));
+            }
+            builder.row(into_dyn_row(record));
+        }
+
+        Ok(builder)
+    }
+}
+
+fn into_row<const WIDTH: usize>(
+    record: Vec<String>,
+    line: usize,
+    delimiter: char,
+) -> Result<Row<String, String, WIDTH>, Error> {
+    if record.len() != WIDTH + 1 {
+        return Err(column_count(WIDTH + 1, record, line, delimiter));
+    }
+    Ok(Row::from_cells(record).expect("the length was checked"))
+}
+
+fn into_dyn_row(record: Vec<String>) -> DynRow<String, String> {
+    DynRow::from_cells(record).expect("every record has a field")
+}
+
+fn column_count(expected: usize, record: Vec<String>, line: usize, delimiter: char) -> Error {
+    let kind = ErrorKind::ColumnCount {
+        expected,
+        found: record.len(),
+    };
+    Error::new(kind, record.join(&delimiter.to_string())).at(line, 1)
+}
+
+/// Splits RFC 4180 records separated by `delimiter`, honoring quoted fields
+/// that contain delimiters, doubled quotes and line breaks. Each record comes
+/// with the line it starts on. Blank lines at the end of the input are
+/// dropped.
+pub(crate) fn parse(input: &str, delimiter: char) -> Result<Vec<(usize, Vec<String>)>, Error> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = None;
+    let mut line = 1;
+    let mut column = 0;
+    let mut start = 1;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        column += 1;
+        if c == '\n' {
+            line += 1;
+            column = 0;
+        }
+
+        if quoted.is_some() {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    column += 1;
+                    field.push('"');
+                }
+                '"' => quoted = None,
+                c => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => quoted = Some((line, column)),
+            c if c == delimiter => record.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                records.push((start, std::mem::take(&mut record)));
+                start = line;
+            }
+            c => field.push(c),
+        }
+    }
+
+    if let Some((line, column)) = quoted {
+        return Err(Error::new(ErrorKind::UnterminatedQuote, field).at(line, column));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push((start, record));
+    }
+    while records
+        .last()
+        .is_some_and(|(_, record)| record.len() == 1 && record[0].is_empty())
+    {
+        records.pop();
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use crate::{Builder, DynBuilder, DynTable, Table};
+
+    #[test]
+    fn writer_quotes_fields_that_need_it() {
+        let mut builder: Builder<&str, &str, &str, 2> = Builder::new();
+        builder.header(("name", ["note", "tab"]));
+        builder.row(("a,b", ["say \"hi\"", "x\ty"]));
+        builder.row(("line\nbreak", ["plain", ""]));
+        let table = builder.finish();
+
+        assert_eq!(
+            table.csv().to_string(),
+            "name,note,tab\r\n\"a,b\",\"say \"\"hi\"\"\",x\ty\r\n\"line\nbreak\",plain,\r\n"
+        );
+        assert_eq!(
+            table.tsv().to_string(),
+            "name\tnote\ttab\r\na,b\t\"say \"\"hi\"\"\"\t\"x\ty\"\r\n\"line\nbreak\"\tplain\t\r\n"
+        );
+    }
+
+    #[test]
+    fn reader_handles_quoted_fields() {
+        let records = parse("a,\"b,c\"\r\n\"say \"\"hi\"\"\",\"two\nlines\"\n3,4", ',').unwrap();
+        let records: Vec<_> = records.into_iter().map(|(_, record)| record).collect();
+        assert_eq!(
+            records,
+            [
+                vec!["a", "b,c"],
+                vec!["say \"hi\"", "two\nlines"],
+                vec!["3", "4"]
+            ]
+        );
+    }
+
+    #[test]
+    fn round_trips_through_csv() {
+        let input = "h,\"x,y\"\r\n\"q\"\"\",\"a\nb\"\r\n";
+        let table: Table<String, String, String, 1> = Builder::from_csv(input).unwrap().finish();
+        assert_eq!(table.csv().to_string(), input);
+    }
+
+    #[test]
+    fn trailing_blank_lines_are_ignored() {
+        let table: DynTable<String, String, String> =
+            DynBuilder::from_csv("a,b\n1,2\n\n\n").unwrap().finish();
+        assert_eq!(table.content.len(), 1);
+
+        let table: Table<String, String, String, 1> =
+            Builder::from_tsv("a\tb\r\n1\t2\r\n\r\n").unwrap().finish();
+        assert_eq!(table.content.len(), 1);
+    }
+}
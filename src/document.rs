@@ -0,0 +1,148 @@
+use crate::parse::{parse_delimiter_row, parse_lines, split_row};
+
+/// Re-renders every pipe table in a markdown document, leaving everything
+/// outside of tables (including fenced code blocks) byte-for-byte unchanged.
+pub fn reformat(document: &str) -> String {
+    let lines: Vec<&str> = document.split_inclusive('\n').collect();
+    let mut output = String::with_capacity(document.len());
+    let mut fence: Option<Fence> = None;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+
+        if let Some(open) = &fence {
+            if open.is_closed_by(line) {
+                fence = None;
+            }
+        } else if let Some(open) = Fence::open(line) {
+            fence = Some(open);
+        } else if let Some(end) = table_end(&lines, i) {
+            match render(&lines[i..end]) {
+                Some(table) => output.push_str(&table),
+                None => output.extend(lines[i..end].iter().copied()),
+            }
+            i = end;
+            continue;
+        }
+
+        output.push_str(line);
+        i += 1;
+    }
+
+    output
+}
+
+/// If a table starts at `lines[start]`, returns the index one past its last
+/// line.
+fn table_end(lines: &[&str], start: usize) -> Option<usize> {
+    let header = split_row(content(lines[start])).filter(|_| indent(lines[start]).len() < 4)?;
+    let alignments = parse_delimiter_row(content(lines.get(start + 1)?))?;
+    if header.len() != alignments.len() {
+        return None;
+    }
+
+    let body = lines[start + 2..]
+        .iter()
+        .take_while(|line| !content(line).trim().is_empty() && split_row(content(line)).is_some())
+        .count();
+    Some(start + 2 + body)
+}
+
+/// Returns `None` for tables with a body row longer than the header, which
+/// are left as they are rather than losing the extra cells.
+fn render(lines: &[&str]) -> Option<String> {
+    let columns = split_row(content(lines[0]))?.len();
+    if lines[2..]
+        .iter()
+        .any(|line| split_row(content(line)).is_some_and(|row| row.len() > columns))
+    {
+        return None;
+    }
+
+    let indent = indent(lines[0]);
+    let newline = if lines[0].ends_with("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let terminated = lines[lines.len() - 1].ends_with('\n');
+
+    let table = parse_lines(lines.iter().map(|line| content(line)))
+        .expect("table_end only accepts a header and a matching delimiter row")
+        .to_string();
+
+    let mut output = String::new();
+    let mut rendered = table.lines().peekable();
+    while let Some(line) = rendered.next() {
+        output.push_str(indent);
+        output.push_str(line);
+        if terminated || rendered.peek().is_some() {
+            output.push_str(newline);
+        }
+    }
+    Some(output)
+}
+
+fn content(line: &str) -> &str {
+    line.trim_end_matches(['\n', '\r'])
+}
+
+fn indent(line: &str) -> &str {
+    &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+}
+
+struct Fence {
+    marker: char,
+    length: usize,
+}
+
+impl Fence {
+    fn open(line: &str) -> Option<Self> {
+        if indent(line).len() >= 4 {
+            return None;
+        }
+
+        let line = line.trim_start();
+        let marker = line.chars().next().filter(|&c| c == '`' || c == '~')?;
+        let length = line.chars().take_while(|&c| c == marker).count();
+        if length < 3 || (marker == '`' && line[length..].contains('`')) {
+            return None;
+        }
+
+        Some(Self { marker, length })
+    }
+
+    fn is_closed_by(&self, line: &str) -> bool {
+        let line = content(line).trim();
+        line.chars().count() >= self.length && line.chars().all(|c| c == self.marker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::reformat;
+
+    #[test]
+    fn reformat_is_idempotent() {
+        let document = "# Title\n\n| a | bb |\n|:-|-:|\n| ccc | d |\n|e|f|\n\ntext\n";
+        let once = reformat(document);
+        assert_eq!(
+            once,
+            "# Title\n\n| a   |  bb |\n| :-- | --: |\n| ccc |   d |\n| e   |   f |\n\ntext\n"
+        );
+        assert_eq!(reformat(&once), once);
+    }
+
+    #[test]
+    fn reformat_leaves_other_text_unchanged() {
+        let document = "intro | with a pipe\r\n\n```\n| a | b |\n|---|---|\n```\n\n    | a | b |\n    |---|---|\nend";
+        assert_eq!(reformat(document), document);
+    }
+
+    #[test]
+    fn reformat_leaves_rows_longer_than_the_header() {
+        let document = "| a | b |\n|---|---|\n| 1 | 2 | 3 |\n";
+        assert_eq!(reformat(document), document);
+    }
+}
@@ -0,0 +1,450 @@
+use std::{fmt::Display, ops::Index};
+
+use crate::{
+    cell_width, Alignment, Border, Boxed, BuildError, Csv, Error, EscapePolicy, Format, Html,
+    Latex, Markdown, NumberFormat, Rendered, Renderer, Row, RowView, Rst, Style, Table, TableData,
+    View, MIN_WIDTH,
+};
+
+#[derive(Debug, Clone)]
+pub struct DynTable<LH, TH, T>
+where
+    LH: Display,
+    TH: Display,
+    T: Display,
+{
+    header: DynRow<LH, TH>,
+    alignments: DynRow<Alignment, Alignment>,
+    formats: Option<DynRow<NumberFormat, NumberFormat>>,
+    pub(crate) content: Vec<DynRow<LH, T>>,
+    widths: DynRow<usize, usize>,
+    format: Format,
+}
+
+impl<LH, TH, T> DynTable<LH, TH, T>
+where
+    LH: Display,
+    TH: Display,
+    T: Display,
+{
+    pub fn set_style(&mut self, style: Style) {
+        self.format.style = style;
+    }
+
+    pub fn set_escape(&mut self, escape: EscapePolicy) {
+        self.try_set_escape(escape)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_set_escape(&mut self, escape: EscapePolicy) -> Result<(), BuildError> {
+        self.widths = self.measure(escape).ok_or(BuildError::RejectedCell)?;
+        self.format.escape = escape;
+        Ok(())
+    }
+
+    pub fn width(&self) -> usize {
+        self.header.content.len()
+    }
+
+    pub fn html(&self) -> Html<'_, LH, TH, T> {
+        Html::new(self.view())
+    }
+
+    pub fn csv(&self) -> Csv<'_, LH, TH, T> {
+        Csv::new(self.view(), ',')
+    }
+
+    pub fn tsv(&self) -> Csv<'_, LH, TH, T> {
+        Csv::new(self.view(), '\t')
+    }
+
+    pub fn latex(&self) -> Latex<'_, LH, TH, T> {
+        Latex::new(self.view())
+    }
+
+    pub fn boxed(&self, border: Border) -> Boxed<'_, LH, TH, T> {
+        Boxed::new(self.view(), border)
+    }
+
+    pub fn rst_grid(&self) -> Result<Rst<'_, LH, TH, T>, Error> {
+        Rst::grid(self.view())
+    }
+
+    pub fn rst_simple(&self) -> Result<Rst<'_, LH, TH, T>, Error> {
+        Rst::simple(self.view())
+    }
+
+    pub fn render<R: Renderer>(&self, renderer: R) -> Rendered<R> {
+        Rendered::new(TableData::new(&self.view()), renderer)
+    }
+
+    /// A table with the same header, alignments, formats and style that holds
+    /// `content` instead.
+    pub(crate) fn with_content(&self, content: Vec<DynRow<LH, T>>) -> Self
+    where
+        LH: Clone,
+        TH: Clone,
+    {
+        let mut table = Self {
+            header: self.header.clone(),
+            alignments: self.alignments.clone(),
+            formats: self.formats.clone(),
+            content,
+            widths: self.widths.clone(),
+            format: self.format,
+        };
+        table.remeasure();
+        table
+    }
+
+    /// Measures every cell again, after rows were removed.
+    pub(crate) fn remeasure(&mut self) {
+        self.widths = self
+            .measure(self.format.escape)
+            .expect("the table's own escape policy accepted every cell");
+    }
+
+    fn measure(&self, escape: EscapePolicy) -> Option<DynRow<usize, usize>> {
+        let header = self.header.widths(escape, None);
+        let rows = self
+            .content
+            .iter()
+            .map(|row| row.widths(escape, self.formats.as_ref()));
+
+        std::iter::once(header)
+            .chain(rows)
+            .try_fold(min_widths(self.width()), |widths, row| {
+                Some(DynRow::max(&widths, &row?))
+            })
+    }
+
+    pub(crate) fn view(&self) -> View<'_, LH, TH, T> {
+        View::new(
+            self.header.view(),
+            self.alignments.view(),
+            self.widths.view(),
+            self.content.iter().map(DynRow::view).collect(),
+            self.formats.as_ref().map(DynRow::view),
+            self.format,
+        )
+    }
+}
+
+impl<LH, TH, T> Display for DynTable<LH, TH, T>
+where
+    LH: Display,
+    TH: Display,
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.render(Markdown).fmt(f)
+    }
+}
+
+impl<LH, TH, T, const WIDTH: usize> From<Table<LH, TH, T, WIDTH>> for DynTable<LH, TH, T>
+where
+    LH: Display,
+    TH: Display,
+    T: Display,
+{
+    fn from(table: Table<LH, TH, T, WIDTH>) -> Self {
+        Self {
+            header: table.header.into(),
+            alignments: table.alignments.into(),
+            formats: table.formats.map(DynRow::from),
+            content: table.content.into_iter().map(DynRow::from).collect(),
+            widths: table.widths.into(),
+            format: table.format,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DynRow<F, R> {
+    header: F,
+    content: Vec<R>,
+}
+
+impl<N, R> From<(N, Vec<R>)> for DynRow<N, R> {
+    fn from(value: (N, Vec<R>)) -> Self {
+        let (header, content) = value;
+        Self { header, content }
+    }
+}
+
+impl<N, R, const WIDTH: usize> From<(N, [R; WIDTH])> for DynRow<N, R> {
+    fn from(value: (N, [R; WIDTH])) -> Self {
+        let (header, content) = value;
+        Self {
+            header,
+            content: content.into(),
+        }
+    }
+}
+
+impl<N, R, const WIDTH: usize> From<Row<N, R, WIDTH>> for DynRow<N, R> {
+    fn from(row: Row<N, R, WIDTH>) -> Self {
+        Self {
+            header: row.header,
+            content: row.content.into(),
+        }
+    }
+}
+
+impl<N, R> DynRow<N, R> {
+    pub fn header(&self) -> &N {
+        &self.header
+    }
+
+    pub fn content(&self) -> &[R] {
+        &self.content
+    }
+
+    pub(crate) fn view(&self) -> RowView<'_, N, R> {
+        RowView::new(&self.header, &self.content)
+    }
+}
+
+impl<N: Display, R: Display> DynRow<N, R> {
+    /// Formats every cell once, or returns `None` if `escape` rejects one.
+    fn widths(
+        &self,
+        escape: EscapePolicy,
+        formats: Option<&DynRow<NumberFormat, NumberFormat>>,
+    ) -> Option<DynRow<usize, usize>> {
+        let mut widths: Vec<usize> = self
+            .view()
+            .formatted(formats.map(DynRow::view))
+            .map(|text| cell_width(&text, escape))
+            .collect::<Option<_>>()?;
+        let header = widths.remove(0);
+        Some((header, widths).into())
+    }
+}
+
+impl<T> DynRow<T, T> {
+    /// Splits `cells` into the row header and the rest, or returns `None` if
+    /// there are no cells.
+    pub(crate) fn from_cells(mut cells: Vec<T>) -> Option<Self> {
+        if cells.is_empty() {
+            return None;
+        }
+        let header = cells.remove(0);
+        Some((header, cells).into())
+    }
+}
+
+impl<T: Ord + Copy> DynRow<T, T> {
+    fn max(lhs: &Self, rhs: &Self) -> Self {
+        Self {
+            header: T::max(lhs.header, rhs.header),
+            content: lhs
+                .content
+                .iter()
+                .zip(&rhs.content)
+                .map(|(&l, &r)| T::max(l, r))
+                .collect(),
+        }
+    }
+}
+
+impl<T> Index<usize> for DynRow<T, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.header,
+            x => &self.content[x - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DynBuilder<LH, TH, T> {
+    header: Option<DynRow<LH, TH>>,
+    alignments: Option<DynRow<Alignment, Alignment>>,
+    formats: Option<DynRow<NumberFormat, NumberFormat>>,
+    content: Vec<DynRow<LH, T>>,
+    widths: Option<DynRow<usize, usize>>,
+    format: Format,
+}
+
+impl<LH, TH, T> DynBuilder<LH, TH, T> {
+    pub fn new() -> Self {
+        Self {
+            header: None,
+            alignments: None,
+            formats: None,
+            content: Vec::new(),
+            widths: None,
+            format: Format::default(),
+        }
+    }
+
+    fn update_widths(&mut self, widths: DynRow<usize, usize>) -> Result<(), BuildError> {
+        let widths = match &self.widths {
+            Some(current) if current.content.len() != widths.content.len() => {
+                return Err(BuildError::WidthMismatch {
+                    expected: current.content.len(),
+                    found: widths.content.len(),
+                });
+            }
+            Some(current) => DynRow::max(current, &widths),
+            None => DynRow::max(&min_widths(widths.content.len()), &widths),
+        };
+        self.widths = Some(widths);
+        Ok(())
+    }
+}
+
+impl<LH, TH, T> Default for DynBuilder<LH, TH, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<LH: Display, TH: Display, T: Display> DynBuilder<LH, TH, T> {
+    pub fn style(&mut self, style: Style) {
+        self.format.style = style;
+    }
+
+    pub fn escape(&mut self, escape: EscapePolicy) {
+        self.try_escape(escape).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_escape(&mut self, escape: EscapePolicy) -> Result<(), BuildError> {
+        self.remeasure(escape)
+    }
+
+    fn remeasure(&mut self, escape: EscapePolicy) -> Result<(), BuildError> {
+        let header = self.header.iter().map(|h| h.widths(escape, None));
+        let rows = self
+            .content
+            .iter()
+            .map(|r| r.widths(escape, self.formats.as_ref()));
+        let widths: Vec<_> = header
+            .chain(rows)
+            .collect::<Option<_>>()
+            .ok_or(BuildError::RejectedCell)?;
+
+        self.format.escape = escape;
+        self.widths = self.widths.as_ref().map(|w| min_widths(w.content.len()));
+        for row in widths {
+            self.update_widths(row)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn header(&mut self, header: impl Into<DynRow<LH, TH>>) {
+        self.try_header(header).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_header(&mut self, header: impl Into<DynRow<LH, TH>>) -> Result<(), BuildError> {
+        if self.header.is_some() {
+            return Err(BuildError::DuplicateHeader);
+        }
+        let header = header.into();
+        let widths = header
+            .widths(self.format.escape, None)
+            .ok_or(BuildError::RejectedCell)?;
+
+        self.update_widths(widths)?;
+        self.header = Some(header);
+        Ok(())
+    }
+
+    pub fn alignments(&mut self, alignments: impl Into<DynRow<Alignment, Alignment>>) {
+        self.try_alignments(alignments)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_alignments(
+        &mut self,
+        alignments: impl Into<DynRow<Alignment, Alignment>>,
+    ) -> Result<(), BuildError> {
+        if self.alignments.is_some() {
+            return Err(BuildError::ConflictingAlignments);
+        }
+        let alignments = alignments.into();
+
+        self.update_widths(min_widths(alignments.content.len()))?;
+        self.alignments = Some(alignments);
+        Ok(())
+    }
+
+    pub fn default_alignments(&mut self) {
+        self.try_default_alignments()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_default_alignments(&mut self) -> Result<(), BuildError> {
+        let width = self.width().ok_or(BuildError::MissingHeader)?;
+        self.try_alignments(default_alignments(width))
+    }
+
+    pub fn formats(&mut self, formats: impl Into<DynRow<NumberFormat, NumberFormat>>) {
+        self.try_formats(formats)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Rows added before are measured again with the new formats.
+    pub fn try_formats(
+        &mut self,
+        formats: impl Into<DynRow<NumberFormat, NumberFormat>>,
+    ) -> Result<(), BuildError> {
+        if self.formats.is_some() {
+            return Err(BuildError::ConflictingFormats);
+        }
+        let formats = formats.into();
+
+        self.update_widths(min_widths(formats.content.len()))?;
+        self.formats = Some(formats);
+        self.remeasure(self.format.escape)
+    }
+
+    pub fn row(&mut self, row: impl Into<DynRow<LH, T>>) {
+        self.try_row(row).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_row(&mut self, row: impl Into<DynRow<LH, T>>) -> Result<(), BuildError> {
+        let row = row.into();
+        let widths = row
+            .widths(self.format.escape, self.formats.as_ref())
+            .ok_or(BuildError::RejectedCell)?;
+
+        self.update_widths(widths)?;
+        self.content.push(row);
+        Ok(())
+    }
+
+    pub fn finish(self) -> DynTable<LH, TH, T> {
+        self.try_finish().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_finish(self) -> Result<DynTable<LH, TH, T>, BuildError> {
+        let header = self.header.ok_or(BuildError::MissingHeader)?;
+        let width = header.content.len();
+        Ok(DynTable {
+            header,
+            alignments: self.alignments.unwrap_or_else(|| default_alignments(width)),
+            formats: self.formats,
+            content: self.content,
+            widths: self.widths.unwrap(),
+            format: self.format,
+        })
+    }
+
+    /// Number of columns after the row header, once a row has fixed it.
+    pub fn width(&self) -> Option<usize> {
+        self.widths.as_ref().map(|widths| widths.content.len())
+    }
+}
+
+fn min_widths(width: usize) -> DynRow<usize, usize> {
+    (MIN_WIDTH, vec![MIN_WIDTH; width]).into()
+}
+
+fn default_alignments(width: usize) -> DynRow<Alignment, Alignment> {
+    (Alignment::None, vec![Alignment::Right; width]).into()
+}
@@ -0,0 +1,128 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildError {
+    DuplicateHeader,
+    MissingHeader,
+    /// Alignments were given more than once, or both explicitly and as
+    /// defaults.
+    ConflictingAlignments,
+    /// Number formats were given more than once.
+    ConflictingFormats,
+    /// A cell holds a pipe or line break while `EscapePolicy::Reject` is set.
+    RejectedCell,
+    /// A row's column count differs from the rows given before it.
+    WidthMismatch {
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildError::DuplicateHeader => write!(f, "table header was set twice"),
+            BuildError::MissingHeader => write!(f, "table has no header"),
+            BuildError::ConflictingAlignments => write!(f, "table alignments were set twice"),
+            BuildError::ConflictingFormats => write!(f, "table number formats were set twice"),
+            BuildError::RejectedCell => {
+                write!(
+                    f,
+                    "cell contains a pipe or line break, which the escape policy rejects"
+                )
+            }
+            BuildError::WidthMismatch { expected, found } => {
+                write!(f, "row has {} columns, expected {}", found, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    kind: ErrorKind,
+    input: String,
+    position: Option<Position>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    InvalidAlignment,
+    MissingHeader,
+    MissingDelimiterRow,
+    ColumnCount {
+        expected: usize,
+        found: usize,
+    },
+    BlankLine,
+    UnterminatedQuote,
+    /// A cell can't be written in the requested output format.
+    Unrepresentable {
+        reason: &'static str,
+    },
+}
+
+/// One-based line and column (in characters) into the parsed text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Error {
+    pub(crate) fn new(kind: ErrorKind, input: impl Into<String>) -> Self {
+        Self {
+            kind,
+            input: input.into(),
+            position: None,
+        }
+    }
+
+    pub(crate) fn at(mut self, line: usize, column: usize) -> Self {
+        self.position = Some(Position { line, column });
+        self
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The text that could not be parsed or rendered: a cell, or a whole line.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(Position { line, column }) = self.position {
+            write!(f, "line {}, column {}: ", line, column)?;
+        }
+
+        match self.kind {
+            ErrorKind::InvalidAlignment => write!(f, "invalid alignment `{}`", self.input),
+            ErrorKind::MissingHeader => write!(f, "expected a header row, found `{}`", self.input),
+            ErrorKind::MissingDelimiterRow => {
+                write!(f, "expected a delimiter row, found `{}`", self.input)
+            }
+            ErrorKind::ColumnCount { expected, found } => write!(
+                f,
+                "expected {} columns, found {} in `{}`",
+                expected, found, self.input
+            ),
+            ErrorKind::BlankLine => write!(f, "unexpected blank line inside table"),
+            ErrorKind::UnterminatedQuote => write!(f, "unterminated quoted field"),
+            ErrorKind::Unrepresentable { reason } => {
+                write!(f, "cannot render cell `{}`: {}", self.input, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,116 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{sort::check_column, DynRow, DynTable, Row, Table};
+
+impl<LH, TH, T, const WIDTH: usize> Table<LH, TH, T, WIDTH>
+where
+    LH: Display,
+    TH: Display,
+    T: Display,
+{
+    /// Keeps only the rows `keep` returns `true` for. Columns shrink to fit
+    /// the rows that are left.
+    pub fn retain(&mut self, keep: impl FnMut(&Row<LH, T, WIDTH>) -> bool) {
+        self.content.retain(keep);
+        self.remeasure();
+    }
+
+    /// Removes every row whose cell in `column` repeats one of an earlier
+    /// row, where column `0` holds the row headers.
+    pub fn dedup_by_column(&mut self, column: usize) {
+        check_column(column, WIDTH);
+        let mut seen = HashSet::new();
+        self.retain(|row| seen.insert(row.view().text(column)));
+    }
+
+    /// A copy of the table with only the rows `keep` returns `true` for.
+    /// Rows that are left out aren't cloned.
+    pub fn filter(&self, mut keep: impl FnMut(&Row<LH, T, WIDTH>) -> bool) -> Self
+    where
+        LH: Clone,
+        TH: Clone,
+        T: Clone,
+    {
+        let content = self.content.iter().filter(|row| keep(row)).cloned();
+        self.with_content(content.collect())
+    }
+}
+
+impl<LH, TH, T> DynTable<LH, TH, T>
+where
+    LH: Display,
+    TH: Display,
+    T: Display,
+{
+    pub fn retain(&mut self, keep: impl FnMut(&DynRow<LH, T>) -> bool) {
+        self.content.retain(keep);
+        self.remeasure();
+    }
+
+    pub fn dedup_by_column(&mut self, column: usize) {
+        check_column(column, self.width());
+        let mut seen = HashSet::new();
+        self.retain(|row| seen.insert(row.view().text(column)));
+    }
+
+    pub fn filter(&self, mut keep: impl FnMut(&DynRow<LH, T>) -> bool) -> Self
+    where
+        LH: Clone,
+        TH: Clone,
+        T: Clone,
+    {
+        let content = self.content.iter().filter(|row| keep(row)).cloned();
+        self.with_content(content.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Builder, DynTable, Table};
+
+    fn table() -> Table<&'static str, &'static str, &'static str, 1> {
+        let mut builder = Builder::new();
+        builder.header(("name", ["note"]));
+        builder.row(("a", ["short"]));
+        builder.row(("b", ["a much longer note"]));
+        builder.row(("a", ["again"]));
+        builder.finish()
+    }
+
+    #[test]
+    fn removing_the_widest_row_narrows_the_column() {
+        let mut table = table();
+        table.retain(|row| !row.content()[0].starts_with("a much"));
+        assert_eq!(
+            table.to_string(),
+            "name |  note\n\
+             ---- | ----:\n\
+             a    | short\n\
+             a    | again\n"
+        );
+    }
+
+    #[test]
+    fn filter_leaves_the_original_alone() {
+        let table = table();
+        let filtered = table.filter(|row| *row.header() == "b");
+        assert_eq!(
+            filtered.to_string(),
+            "name |               note\n\
+             ---- | -----------------:\n\
+             b    | a much longer note\n"
+        );
+        assert_eq!(table.content.len(), 3);
+
+        let table = DynTable::from(table);
+        assert_eq!(table.filter(|row| *row.header() == "a").content.len(), 2);
+    }
+
+    #[test]
+    fn dedup_keeps_the_first_occurrence() {
+        let mut table = DynTable::from(table());
+        table.dedup_by_column(0);
+        let notes: Vec<&str> = table.content.iter().map(|row| row.content()[0]).collect();
+        assert_eq!(notes, ["short", "a much longer note"]);
+    }
+}
@@ -0,0 +1,127 @@
+use std::fmt::Display;
+
+use crate::{Alignment, View};
+
+/// Renders a table as an HTML `<table>`, created by `Table::html`.
+pub struct Html<'t, LH, TH, T> {
+    view: View<'t, LH, TH, T>,
+}
+
+impl<'t, LH, TH, T> Html<'t, LH, TH, T> {
+    pub(crate) fn new(view: View<'t, LH, TH, T>) -> Self {
+        Self { view }
+    }
+}
+
+impl<LH, TH, T> Display for Html<'_, LH, TH, T>
+where
+    LH: Display,
+    TH: Display,
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let alignments: Vec<Alignment> = self.view.column_alignments().collect();
+
+        writeln!(f, "<table>")?;
+        writeln!(f, "  <thead>")?;
+        writeln!(f, "    <tr>")?;
+        for (text, &align) in self.view.header.texts().zip(&alignments) {
+            writeln!(
+                f,
+                "      <th scope=\"col\"{}>{}</th>",
+                Style(align),
+                Escaped(&text)
+            )?;
+        }
+        writeln!(f, "    </tr>")?;
+        writeln!(f, "  </thead>")?;
+
+        writeln!(f, "  <tbody>")?;
+        for &row in &self.view.rows {
+            writeln!(f, "    <tr>")?;
+            for (i, (text, &align)) in row
+                .formatted(self.view.formats)
+                .zip(&alignments)
+                .enumerate()
+            {
+                if i == 0 {
+                    writeln!(
+                        f,
+                        "      <th scope=\"row\"{}>{}</th>",
+                        Style(align),
+                        Escaped(&text)
+                    )?;
+                } else {
+                    writeln!(f, "      <td{}>{}</td>", Style(align), Escaped(&text))?;
+                }
+            }
+            writeln!(f, "    </tr>")?;
+        }
+        writeln!(f, "  </tbody>")?;
+        writeln!(f, "</table>")
+    }
+}
+
+struct Style(Alignment);
+
+impl Display for Style {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let align = match self.0 {
+            Alignment::None => return Ok(()),
+            Alignment::Left => "left",
+            Alignment::Center => "center",
+            Alignment::Right | Alignment::Decimal => "right",
+        };
+        write!(f, " style=\"text-align:{}\"", align)
+    }
+}
+
+struct Escaped<'s>(&'s str);
+
+impl Display for Escaped<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut rest = self.0;
+        while let Some(i) = rest.find(['&', '<', '>', '"', '\'']) {
+            f.write_str(&rest[..i])?;
+            f.write_str(match rest.as_bytes()[i] {
+                b'&' => "&amp;",
+                b'<' => "&lt;",
+                b'>' => "&gt;",
+                b'"' => "&quot;",
+                _ => "&#39;",
+            })?;
+            rest = &rest[i + 1..];
+        }
+        f.write_str(rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Builder;
+
+    #[test]
+    fn cells_are_escaped() {
+        let mut builder: Builder<&str, &str, &str, 1> = Builder::new();
+        builder.header(("<b>", ["\"q\""]));
+        builder.row(("a&b", ["it's <i>"]));
+
+        assert_eq!(
+            builder.finish().html().to_string(),
+            "<table>\n\
+             \x20 <thead>\n\
+             \x20   <tr>\n\
+             \x20     <th scope=\"col\">&lt;b&gt;</th>\n\
+             \x20     <th scope=\"col\" style=\"text-align:right\">&quot;q&quot;</th>\n\
+             \x20   </tr>\n\
+             \x20 </thead>\n\
+             \x20 <tbody>\n\
+             \x20   <tr>\n\
+             \x20     <th scope=\"row\">a&amp;b</th>\n\
+             \x20     <td style=\"text-align:right\">it&#39;s &lt;i&gt;</td>\n\
+             \x20   </tr>\n\
+             \x20 </tbody>\n\
+             </table>\n"
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt::Display;
+
+use crate::{Alignment, View};
+
+/// Renders a table as a LaTeX `tabular` environment, created by
+/// `Table::latex`.
+pub struct Latex<'t, LH, TH, T> {
+    view: View<'t, LH, TH, T>,
+    booktabs: bool,
+}
+
+impl<'t, LH, TH, T> Latex<'t, LH, TH, T> {
+    pub(crate) fn new(view: View<'t, LH, TH, T>) -> Self {
+        Self {
+            view,
+            booktabs: false,
+        }
+    }
+
+    /// Uses `\toprule`, `\midrule` and `\bottomrule` from the `booktabs`
+    /// package instead of `\hline`.
+    pub fn booktabs(mut self) -> Self {
+        self.booktabs = true;
+        self
+    }
+
+    fn rule(&self, f: &mut std::fmt::Formatter<'_>, booktabs: &str) -> std::fmt::Result {
+        if self.booktabs {
+            writeln!(f, "\\{}", booktabs)
+        } else {
+            writeln!(f, "\\hline")
+        }
+    }
+}
+
+impl<LH, TH, T> Display for Latex<'_, LH, TH, T>
+where
+    LH: Display,
+    TH: Display,
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn row(
+            f: &mut std::fmt::Formatter<'_>,
+            cells: impl Iterator<Item = String>,
+        ) -> std::fmt::Result {
+            for (i, cell) in cells.enumerate() {
+                if i > 0 {
+                    write!(f, " & ")?;
+                }
+                write!(f, "{}", Escaped(&cell))?;
+            }
+            writeln!(f, " \\\\")
+        }
+
+        let spec: String = self
+            .view
+            .column_alignments()
+            .map(|align| match align {
+                Alignment::None | Alignment::Left => 'l',
+                Alignment::Center => 'c',
+                Alignment::Right | Alignment::Decimal => 'r',
+            })
+            .collect();
+
+        writeln!(f, "\\begin{{tabular}}{{{}}}", spec)?;
+        self.rule(f, "toprule")?;
+        row(f, self.view.header.texts())?;
+        self.rule(f, "midrule")?;
+        for &content in &self.view.rows {
+            row(f, content.formatted(self.view.formats))?;
+        }
+        self.rule(f, "bottomrule")?;
+        writeln!(f, "\\end{{tabular}}")
+    }
+}
+
+struct Escaped<'s>(&'s str);
+
+impl Display for Escaped<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' | '%' | '$' | '#' | '_' | '{' | '}' => write!(f, "\\{}", c)?,
+                '~' => f.write_str("\\textasciitilde{}")?,
+                '^' => f.write_str("\\textasciicircum{}")?,
+                '\\' => f.write_str("\\textbackslash{}")?,
+                '\r' | '\n' => f.write_str(" ")?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Builder;
+
+    #[test]
+    fn special_characters_are_escaped() {
+        let mut builder: Builder<&str, &str, &str, 1> = Builder::new();
+        builder.header(("a & b", ["100%"]));
+        builder.row(("$x_1$ #{y}", ["~^\\"]));
+
+        assert_eq!(
+            builder.finish().latex().booktabs().to_string(),
+            "\\begin{tabular}{lr}\n\
+             \\toprule\n\
+             a \\& b & 100\\% \\\\\n\
+             \\midrule\n\
+             \\$x\\_1\\$ \\#\\{y\\} & \\textasciitilde{}\\textasciicircum{}\\textbackslash{} \\\\\n\
+             \\bottomrule\n\
+             \\end{tabular}\n"
+        );
+    }
+}
@@ -1,84 +1,334 @@
-use std::{fmt::Display, ops::Index, str::FromStr};
+use std::{borrow::Cow, fmt::Display, ops::Index, str::FromStr};
+
+mod boxed;
+mod csv;
+mod document;
+mod dynamic;
+mod error;
+mod filter;
+mod html;
+mod latex;
+mod markup;
+mod number;
+mod parse;
+#[cfg(feature = "serde")]
+mod records;
+mod render;
+mod rst;
+#[cfg(feature = "serde")]
+mod serialize;
+mod sort;
+mod view;
+
+pub use boxed::{Border, Boxed};
+pub use csv::Csv;
+pub use document::reformat;
+pub use dynamic::{DynBuilder, DynRow, DynTable};
+pub use error::{BuildError, Error, ErrorKind, Position};
+pub use html::Html;
+pub use latex::Latex;
+pub use markup::{AsciiDoc, MediaWiki, OrgMode};
+pub use number::NumberFormat;
+pub use render::{Markdown, Rendered, Renderer, TableData};
+pub use rst::Rst;
+pub use sort::Order;
+
+#[cfg(feature = "derive")]
+pub use mdtable_derive::MdRow;
+
+use view::{RowView, View};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 #[derive(Debug, Clone)]
 pub struct Table<LH, TH, T, const WIDTH: usize>
 where
-    LH: AsRef<str>,
-    TH: AsRef<str>,
-    T: AsRef<str>,
+    LH: Display,
+    TH: Display,
+    T: Display,
 {
     header: Row<LH, TH, WIDTH>,
     alignments: Row<Alignment, Alignment, WIDTH>,
+    formats: Option<Row<NumberFormat, NumberFormat, WIDTH>>,
     content: Vec<Row<LH, T, WIDTH>>,
     widths: Row<usize, usize, WIDTH>,
+    format: Format,
 }
 
-impl<LH, TH, T, const WIDTH: usize> Display for Table<LH, TH, T, WIDTH>
+impl<LH, TH, T, const WIDTH: usize> Table<LH, TH, T, WIDTH>
 where
-    LH: AsRef<str>,
-    TH: AsRef<str>,
-    T: AsRef<str>,
+    LH: Display,
+    TH: Display,
+    T: Display,
 {
-    fn fmt<'x>(&'x self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let make_format_row =
-            |row: &'x Row<LH, T, WIDTH>| FormatRow::new(row, &self.widths, &self.alignments);
-
-        writeln!(
-            f,
-            "{}",
-            FormatRow::new(&self.header, &self.widths, &self.alignments)
-        )?;
-
-        writeln!(
-            f,
-            "{}",
-            FormatRow::new(&self.alignments, &self.widths, &self.alignments)
-        )?;
-
-        for row in &self.content {
-            writeln!(f, "{}", make_format_row(row))?;
-        }
+    pub fn set_style(&mut self, style: Style) {
+        self.format.style = style;
+    }
+
+    pub fn set_escape(&mut self, escape: EscapePolicy) {
+        self.try_set_escape(escape)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
 
+    /// Measures every cell again with the new policy, leaving the table
+    /// unchanged if the policy rejects one.
+    pub fn try_set_escape(&mut self, escape: EscapePolicy) -> Result<(), BuildError> {
+        self.widths = self.measure(escape).ok_or(BuildError::RejectedCell)?;
+        self.format.escape = escape;
         Ok(())
     }
+
+    pub fn html(&self) -> Html<'_, LH, TH, T> {
+        Html::new(self.view())
+    }
+
+    pub fn csv(&self) -> Csv<'_, LH, TH, T> {
+        Csv::new(self.view(), ',')
+    }
+
+    pub fn tsv(&self) -> Csv<'_, LH, TH, T> {
+        Csv::new(self.view(), '\t')
+    }
+
+    pub fn latex(&self) -> Latex<'_, LH, TH, T> {
+        Latex::new(self.view())
+    }
+
+    pub fn boxed(&self, border: Border) -> Boxed<'_, LH, TH, T> {
+        Boxed::new(self.view(), border)
+    }
+
+    pub fn rst_grid(&self) -> Result<Rst<'_, LH, TH, T>, Error> {
+        Rst::grid(self.view())
+    }
+
+    pub fn rst_simple(&self) -> Result<Rst<'_, LH, TH, T>, Error> {
+        Rst::simple(self.view())
+    }
+
+    pub fn render<R: Renderer>(&self, renderer: R) -> Rendered<R> {
+        Rendered::new(TableData::new(&self.view()), renderer)
+    }
+
+    /// A table with the same header, alignments, formats and style that holds
+    /// `content` instead.
+    fn with_content(&self, content: Vec<Row<LH, T, WIDTH>>) -> Self
+    where
+        LH: Clone,
+        TH: Clone,
+    {
+        let mut table = Self {
+            header: self.header.clone(),
+            alignments: self.alignments.clone(),
+            formats: self.formats.clone(),
+            content,
+            widths: self.widths.clone(),
+            format: self.format,
+        };
+        table.remeasure();
+        table
+    }
+
+    /// Measures every cell again, after rows were removed.
+    fn remeasure(&mut self) {
+        self.widths = self
+            .measure(self.format.escape)
+            .expect("the table's own escape policy accepted every cell");
+    }
+
+    /// Column widths with `escape`, or `None` if it rejects a cell.
+    fn measure(&self, escape: EscapePolicy) -> Option<Row<usize, usize, WIDTH>> {
+        let header = self.header.widths(escape, None);
+        let rows = self
+            .content
+            .iter()
+            .map(|row| row.widths(escape, self.formats.as_ref()));
+
+        let min_widths = (MIN_WIDTH, [MIN_WIDTH; WIDTH]).into();
+        std::iter::once(header)
+            .chain(rows)
+            .try_fold(min_widths, |widths, row| Some(Row::max(&widths, &row?)))
+    }
+
+    fn view(&self) -> View<'_, LH, TH, T> {
+        View::new(
+            self.header.view(),
+            self.alignments.view(),
+            self.widths.view(),
+            self.content.iter().map(Row::view).collect(),
+            self.formats.as_ref().map(Row::view),
+            self.format,
+        )
+    }
+}
+
+impl<LH, TH, T, const WIDTH: usize> Display for Table<LH, TH, T, WIDTH>
+where
+    LH: Display,
+    TH: Display,
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.render(Markdown).fmt(f)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum Alignment {
+    None,
     Left,
     Center,
     Right,
+    /// Right-aligned in markdown, with decimal points lined up in padded
+    /// output.
+    Decimal,
 }
 
 impl FromStr for Alignment {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "---" | ":---" => Ok(Self::Left),
-            ":---:" => Ok(Self::Center),
-            "---:" => Ok(Self::Right),
-            _ => Err(()),
+        let (left, rest) = match s.strip_prefix(':') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+        let (right, dashes) = match rest.strip_suffix(':') {
+            Some(dashes) => (true, dashes),
+            None => (false, rest),
+        };
+
+        if dashes.is_empty() || dashes.bytes().any(|b| b != b'-') {
+            return Err(Error::new(ErrorKind::InvalidAlignment, s));
         }
+
+        Ok(match (left, right) {
+            (false, false) => Self::None,
+            (true, false) => Self::Left,
+            (true, true) => Self::Center,
+            (false, true) => Self::Right,
+        })
     }
 }
 
 impl Alignment {
     fn default_row<const WIDTH: usize>() -> Row<Self, Self, WIDTH> {
-        (Self::Left, [Self::Right; WIDTH]).into()
+        (Self::None, [Self::Right; WIDTH]).into()
+    }
+
+    fn separator(self, width: usize) -> String {
+        let dashes = |n: usize| "-".repeat(n);
+        match self {
+            Alignment::None => dashes(width),
+            Alignment::Left => format!(":{}", dashes(width - 1)),
+            Alignment::Center => format!(":{}:", dashes(width - 2)),
+            Alignment::Right | Alignment::Decimal => format!("{}:", dashes(width - 1)),
+        }
     }
 }
 
 impl AsRef<str> for Alignment {
     fn as_ref(&self) -> &str {
         match self {
-            Alignment::Left => "---",
+            Alignment::None => "---",
+            Alignment::Left => ":---",
             Alignment::Center => ":---:",
-            Alignment::Right => "---:",
+            Alignment::Right | Alignment::Decimal => "---:",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum EscapePolicy {
+    /// Pipes become `\|` and line breaks become `<br>`.
+    #[default]
+    Escape,
+    /// Cells containing pipes or line breaks are refused by the `Builder`.
+    Reject,
+}
+
+impl EscapePolicy {
+    const SPECIAL: [char; 3] = ['|', '\n', '\r'];
+
+    fn accepts(self, s: &str) -> bool {
+        self != Self::Reject || !s.contains(Self::SPECIAL)
+    }
+
+    fn apply(self, s: &str) -> Cow<'_, str> {
+        match self {
+            Self::Escape if s.contains(Self::SPECIAL) => Cow::Owned(
+                s.replace('|', "\\|")
+                    .replace("\r\n", "<br>")
+                    .replace(['\r', '\n'], "<br>"),
+            ),
+            _ => Cow::Borrowed(s),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Style {
+    /// `a | b | c`
+    #[default]
+    Bare,
+    /// `| a | b | c |`
+    Github,
+    /// `a|b|c`, without padding
+    Compact,
+}
+
+impl Style {
+    fn borders(self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            Self::Bare => ("", " | ", ""),
+            Self::Github => ("| ", " | ", " |"),
+            Self::Compact => ("", "|", ""),
+        }
+    }
+
+    fn pads(self) -> bool {
+        self != Self::Compact
+    }
+
+    fn write_row(
+        self,
+        f: &mut dyn std::fmt::Write,
+        width: usize,
+        mut cell: impl FnMut(&mut dyn std::fmt::Write, usize) -> std::fmt::Result,
+    ) -> std::fmt::Result {
+        let (left, separator, right) = self.borders();
+
+        write!(f, "{}", left)?;
+        cell(f, 0)?;
+        for i in 1..=width {
+            write!(f, "{}", separator)?;
+            cell(f, i)?;
         }
+        write!(f, "{}", right)
     }
 }
 
+#[derive(Debug, Clone, Copy, Default)]
+struct Format {
+    style: Style,
+    escape: EscapePolicy,
+}
+
 #[derive(Debug, Clone)]
 pub struct Row<F, R, const WIDTH: usize> {
     header: F,
@@ -106,28 +356,58 @@ impl<N, R, const WIDTH: usize> From<(N, [R; WIDTH])> for Row<N, R, WIDTH> {
     }
 }
 
-impl<N: AsRef<str>, R: AsRef<str>, const WIDTH: usize> Row<N, R, WIDTH> {
-    fn widths(&self) -> Row<usize, usize, WIDTH> {
-        let mut widths = Row::default();
+impl<N, R, const WIDTH: usize> Row<N, R, WIDTH> {
+    pub fn header(&self) -> &N {
+        &self.header
+    }
 
-        widths.header = self.header.as_ref().len();
-        for i in 0..WIDTH {
-            widths.content[i] = self.content[i].as_ref().len();
-        }
+    pub fn content(&self) -> &[R; WIDTH] {
+        &self.content
+    }
 
-        widths
+    fn view(&self) -> RowView<'_, N, R> {
+        RowView::new(&self.header, &self.content)
     }
 }
 
-impl<T: Ord + Copy + Default, const WIDTH: usize> Row<T, T, WIDTH> {
-    fn max(lhs: &Self, rhs: &Self) -> Self {
-        let mut content = [T::default(); WIDTH];
-        for i in 0..WIDTH {
-            content[i] = T::max(lhs.content[i], rhs.content[i]);
+impl<N: Display, R: Display, const WIDTH: usize> Row<N, R, WIDTH> {
+    /// Formats every cell once, or returns `None` if `escape` rejects one.
+    fn widths(
+        &self,
+        escape: EscapePolicy,
+        formats: Option<&Row<NumberFormat, NumberFormat, WIDTH>>,
+    ) -> Option<Row<usize, usize, WIDTH>> {
+        let mut widths: Vec<usize> = self
+            .view()
+            .formatted(formats.map(Row::view))
+            .map(|text| cell_width(&text, escape))
+            .collect::<Option<_>>()?;
+        let header = widths.remove(0);
+        Some(Row {
+            header,
+            content: widths.try_into().ok()?,
+        })
+    }
+}
+
+impl<T, const WIDTH: usize> Row<T, T, WIDTH> {
+    /// Splits `cells` into the row header and the rest, or returns `None`
+    /// unless there are exactly `WIDTH + 1` of them.
+    fn from_cells(mut cells: Vec<T>) -> Option<Self> {
+        if cells.len() != WIDTH + 1 {
+            return None;
         }
+        let header = cells.remove(0);
+        let content = cells.try_into().ok()?;
+        Some(Self { header, content })
+    }
+}
+
+impl<T: Ord + Copy, const WIDTH: usize> Row<T, T, WIDTH> {
+    fn max(lhs: &Self, rhs: &Self) -> Self {
         Self {
             header: T::max(lhs.header, rhs.header),
-            content,
+            content: std::array::from_fn(|i| T::max(lhs.content[i], rhs.content[i])),
         }
     }
 }
@@ -143,70 +423,112 @@ impl<T, const WIDTH: usize> Index<usize> for Row<T, T, WIDTH> {
     }
 }
 
-struct FormatRow<'c, 'w, 'a, H, C, const WIDTH: usize> {
-    content: &'c Row<H, C, WIDTH>,
-    widths: &'w Row<usize, usize, WIDTH>,
-    alignments: &'a Row<Alignment, Alignment, WIDTH>,
+/// A type that lays out as one table row, usually implemented with
+/// `#[derive(MdRow)]` from the `derive` feature.
+pub trait MdRow<const WIDTH: usize>: Into<Row<String, String, WIDTH>> {
+    /// Column titles, for `Builder::header`.
+    fn header() -> Row<String, String, WIDTH>;
+
+    fn alignments() -> Row<Alignment, Alignment, WIDTH>;
 }
 
-impl<'c, 'w, 'a, H, C, const WIDTH: usize> FormatRow<'c, 'w, 'a, H, C, WIDTH> {
-    fn new(
-        content: &'c Row<H, C, WIDTH>,
-        widths: &'w Row<usize, usize, WIDTH>,
-        alignments: &'a Row<Alignment, Alignment, WIDTH>,
-    ) -> Self {
-        Self {
-            content,
-            widths,
-            alignments,
+fn write_padded(
+    f: &mut dyn std::fmt::Write,
+    data: &str,
+    width: usize,
+    align: Alignment,
+) -> std::fmt::Result {
+    let padding = width.saturating_sub(display_width(data));
+    let (left, right) = match align {
+        Alignment::None | Alignment::Left => (0, padding),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+        Alignment::Right | Alignment::Decimal => (padding, 0),
+    };
+    write!(f, "{:left$}{}{:right$}", "", data, "")
+}
+
+/// Narrowest column that still fits a separator such as `:-:`.
+const MIN_WIDTH: usize = 3;
+
+/// Number of terminal columns `s` occupies in a monospace font, measured per
+/// grapheme cluster so combining marks and emoji sequences count once.
+fn display_width(s: &str) -> usize {
+    s.graphemes(true).map(UnicodeWidthStr::width).sum()
+}
+
+/// The lines of a cell, split at any kind of line break.
+fn lines(text: &str) -> Vec<&str> {
+    text.split("\r\n")
+        .flat_map(|line| line.split(['\r', '\n']))
+        .collect()
+}
+
+/// Width of every column for renderers that write cells unescaped and line
+/// by line, never less than `MIN_WIDTH`.
+fn line_widths<'c>(rows: impl IntoIterator<Item = &'c [Vec<&'c str>]>) -> Vec<usize> {
+    let mut widths = Vec::new();
+    for row in rows {
+        widths.resize(usize::max(widths.len(), row.len()), MIN_WIDTH);
+        for (width, cell) in widths.iter_mut().zip(row) {
+            for line in cell {
+                *width = usize::max(*width, display_width(line));
+            }
         }
     }
+    widths
 }
 
-impl<'c, 'w, 'a, H: AsRef<str>, C: AsRef<str>, const WIDTH: usize> Display
-    for FormatRow<'c, 'w, 'a, H, C, WIDTH>
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        fn cell(
-            f: &mut std::fmt::Formatter<'_>,
-            data: impl AsRef<str>,
-            width: usize,
-            align: Alignment,
-        ) -> std::fmt::Result {
-            match align {
-                Alignment::Left => write!(f, "{:<width$}", data.as_ref(), width = width),
-                Alignment::Center => write!(f, "{:^width$}", data.as_ref(), width = width),
-                Alignment::Right => write!(f, "{:>width$}", data.as_ref(), width = width),
-            }
+/// Widths of the integer part and of the decimal point and fraction of the
+/// widest cells in an `Alignment::Decimal` column.
+#[derive(Debug, Clone, Copy, Default)]
+struct Decimals {
+    integer: usize,
+    fraction: usize,
+}
+
+impl Decimals {
+    fn measure(text: &str) -> Self {
+        let (integer, fraction) = text.split_at(text.find('.').unwrap_or(text.len()));
+        Self {
+            integer: display_width(integer),
+            fraction: display_width(fraction),
         }
+    }
 
-        cell(
-            f,
-            &self.content.header,
-            self.widths.header,
-            self.alignments.header,
-        )?;
-
-        for i in 0..WIDTH {
-            write!(f, " | ")?;
-            cell(
-                f,
-                &self.content.content[i],
-                self.widths.content[i],
-                self.alignments.content[i],
-            )?;
+    fn max(lhs: Self, rhs: Self) -> Self {
+        Self {
+            integer: lhs.integer.max(rhs.integer),
+            fraction: lhs.fraction.max(rhs.fraction),
         }
+    }
 
-        Ok(())
+    fn width(self) -> usize {
+        self.integer + self.fraction
     }
+
+    fn align(self, text: &str) -> String {
+        let own = Self::measure(text);
+        let left = self.integer.saturating_sub(own.integer);
+        let right = self.fraction.saturating_sub(own.fraction);
+        format!("{:left$}{}{:right$}", "", text, "")
+    }
+}
+
+/// Display width of `text` once escaped, or `None` if `escape` rejects it.
+fn cell_width(text: &str, escape: EscapePolicy) -> Option<usize> {
+    escape
+        .accepts(text)
+        .then(|| display_width(&escape.apply(text)))
 }
 
 #[derive(Debug, Clone)]
 pub struct Builder<LH, TH, T, const WIDTH: usize> {
     header: Option<Row<LH, TH, WIDTH>>,
     alignments: Option<Row<Alignment, Alignment, WIDTH>>,
+    formats: Option<Row<NumberFormat, NumberFormat, WIDTH>>,
     content: Vec<Row<LH, T, WIDTH>>,
     widths: Row<usize, usize, WIDTH>,
+    format: Format,
 }
 
 impl<LH, TH, T, const WIDTH: usize> Builder<LH, TH, T, WIDTH> {
@@ -214,8 +536,10 @@ impl<LH, TH, T, const WIDTH: usize> Builder<LH, TH, T, WIDTH> {
         Self {
             header: None,
             alignments: None,
+            formats: None,
             content: Vec::new(),
-            widths: (0, [0; WIDTH]).into(),
+            widths: (MIN_WIDTH, [MIN_WIDTH; WIDTH]).into(),
+            format: Format::default(),
         }
     }
 
@@ -224,41 +548,255 @@ impl<LH, TH, T, const WIDTH: usize> Builder<LH, TH, T, WIDTH> {
     }
 }
 
-impl<LH: AsRef<str>, TH: AsRef<str>, T: AsRef<str>, const WIDTH: usize> Builder<LH, TH, T, WIDTH> {
+impl<LH, TH, T, const WIDTH: usize> Default for Builder<LH, TH, T, WIDTH> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<LH: Display, TH: Display, T: Display, const WIDTH: usize> Builder<LH, TH, T, WIDTH> {
+    pub fn style(&mut self, style: Style) {
+        self.format.style = style;
+    }
+
+    pub fn escape(&mut self, escape: EscapePolicy) {
+        self.try_escape(escape).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_escape(&mut self, escape: EscapePolicy) -> Result<(), BuildError> {
+        self.remeasure(escape)
+    }
+
+    fn remeasure(&mut self, escape: EscapePolicy) -> Result<(), BuildError> {
+        let header = self.header.iter().map(|h| h.widths(escape, None));
+        let rows = self
+            .content
+            .iter()
+            .map(|r| r.widths(escape, self.formats.as_ref()));
+        let widths: Vec<_> = header
+            .chain(rows)
+            .collect::<Option<_>>()
+            .ok_or(BuildError::RejectedCell)?;
+
+        self.format.escape = escape;
+        self.widths = (MIN_WIDTH, [MIN_WIDTH; WIDTH]).into();
+        for row in widths {
+            self.update_widths(row);
+        }
+
+        Ok(())
+    }
+
     pub fn header(&mut self, header: impl Into<Row<LH, TH, WIDTH>>) {
-        assert!(self.header.is_none());
+        self.try_header(header).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_header(&mut self, header: impl Into<Row<LH, TH, WIDTH>>) -> Result<(), BuildError> {
+        if self.header.is_some() {
+            return Err(BuildError::DuplicateHeader);
+        }
         let header = header.into();
+        let widths = header
+            .widths(self.format.escape, None)
+            .ok_or(BuildError::RejectedCell)?;
 
-        self.update_widths(header.widths());
+        self.update_widths(widths);
         self.header = Some(header);
+        Ok(())
     }
 
     pub fn alignments(&mut self, alignments: impl Into<Row<Alignment, Alignment, WIDTH>>) {
-        assert!(self.alignments.is_none());
-        let alignments = alignments.into();
+        self.try_alignments(alignments)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        self.update_widths(alignments.widths());
-        self.alignments = Some(alignments);
+    pub fn try_alignments(
+        &mut self,
+        alignments: impl Into<Row<Alignment, Alignment, WIDTH>>,
+    ) -> Result<(), BuildError> {
+        if self.alignments.is_some() {
+            return Err(BuildError::ConflictingAlignments);
+        }
+
+        self.alignments = Some(alignments.into());
+        Ok(())
     }
 
     pub fn default_alignments(&mut self) {
-        assert!(self.alignments.is_none());
-        self.alignments = Some(Alignment::default_row());
+        self.try_default_alignments()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_default_alignments(&mut self) -> Result<(), BuildError> {
+        self.try_alignments(Alignment::default_row())
+    }
+
+    pub fn formats(&mut self, formats: impl Into<Row<NumberFormat, NumberFormat, WIDTH>>) {
+        self.try_formats(formats)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Rows added before are measured again with the new formats.
+    pub fn try_formats(
+        &mut self,
+        formats: impl Into<Row<NumberFormat, NumberFormat, WIDTH>>,
+    ) -> Result<(), BuildError> {
+        if self.formats.is_some() {
+            return Err(BuildError::ConflictingFormats);
+        }
+
+        self.formats = Some(formats.into());
+        self.remeasure(self.format.escape)
     }
 
     pub fn row(&mut self, row: impl Into<Row<LH, T, WIDTH>>) {
+        self.try_row(row).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_row(&mut self, row: impl Into<Row<LH, T, WIDTH>>) -> Result<(), BuildError> {
         let row = row.into();
+        let widths = row
+            .widths(self.format.escape, self.formats.as_ref())
+            .ok_or(BuildError::RejectedCell)?;
 
-        self.update_widths(row.widths());
+        self.update_widths(widths);
         self.content.push(row);
+        Ok(())
     }
 
     pub fn finish(self) -> Table<LH, TH, T, WIDTH> {
-        Table {
-            header: self.header.unwrap(),
+        self.try_finish().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    pub fn try_finish(self) -> Result<Table<LH, TH, T, WIDTH>, BuildError> {
+        Ok(Table {
+            header: self.header.ok_or(BuildError::MissingHeader)?,
             alignments: self.alignments.unwrap_or_else(Alignment::default_row),
+            formats: self.formats,
             content: self.content,
             widths: self.widths,
+            format: self.format,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{display_width, Alignment, Border, BuildError, Builder, EscapePolicy, Style};
+
+    #[test]
+    fn wide_and_combining_cells_line_up() {
+        let mut builder: Builder<&str, &str, &str, 1> = Builder::new();
+        builder.header(("name", ["city"]));
+        builder.row(("Müller", ["東京"]));
+        builder.row(("Mu\u{308}ller", ["👍🏽"]));
+        let table = builder.finish().to_string();
+
+        assert_eq!(
+            table,
+            "name   | city\n\
+             ------ | ---:\n\
+             Müller | 東京\n\
+             Mu\u{308}ller |   👍🏽\n"
+        );
+        assert!(table.lines().all(|line| display_width(line) == 13));
+    }
+
+    #[test]
+    fn pipes_and_line_breaks_are_escaped() {
+        let mut builder: Builder<&str, &str, &str, 1> = Builder::new();
+        builder.style(Style::Github);
+        builder.header(("a|b", ["c"]));
+        builder.row(("x\ny", ["1\r\n2"]));
+
+        assert_eq!(
+            builder.finish().to_string(),
+            "| a\\|b   |      c |\n\
+             | ------ | -----: |\n\
+             | x<br>y | 1<br>2 |\n"
+        );
+    }
+
+    #[test]
+    fn reject_refuses_pipes_and_line_breaks() {
+        let mut builder: Builder<&str, &str, &str, 1> = Builder::new();
+        builder.escape(EscapePolicy::Reject);
+        builder.header(("a", ["b"]));
+
+        assert_eq!(
+            builder.try_row(("x|y", ["1"])),
+            Err(BuildError::RejectedCell)
+        );
+        assert_eq!(
+            builder.try_row(("x", ["1\n2"])),
+            Err(BuildError::RejectedCell)
+        );
+        assert_eq!(builder.try_row(("x", ["1"])), Ok(()));
+    }
+
+    #[test]
+    fn failed_set_escape_leaves_table_unchanged() {
+        let mut builder: Builder<&str, &str, &str, 1> = Builder::new();
+        builder.header(("a", ["b"]));
+        builder.row(("x|y", ["1"]));
+        let mut table = builder.finish();
+        let before = table.to_string();
+
+        assert_eq!(
+            table.try_set_escape(EscapePolicy::Reject),
+            Err(BuildError::RejectedCell)
+        );
+        assert_eq!(table.to_string(), before);
+        assert_eq!(before, "a    |   b\n---- | --:\nx\\|y |   1\n");
+    }
+
+    fn decimal_table(
+        cells: [&'static str; 3],
+    ) -> crate::Table<&'static str, &'static str, &'static str, 1> {
+        let mut builder = Builder::new();
+        builder.header(("item", ["price"]));
+        builder.alignments((Alignment::None, [Alignment::Decimal]));
+        for (item, price) in ["a", "b", "c"].into_iter().zip(cells) {
+            builder.row((item, [price]));
         }
+        builder.finish()
+    }
+
+    #[test]
+    fn decimal_points_line_up() {
+        let table = decimal_table(["1.5", "12.25", "100"]);
+
+        assert_eq!(
+            table.to_string(),
+            "item |  price\n\
+             ---- | -----:\n\
+             a    |   1.5 \n\
+             b    |  12.25\n\
+             c    | 100   \n"
+        );
+        assert_eq!(
+            table.boxed(Border::Ascii).to_string(),
+            "+------+--------+\n\
+             | item |  price |\n\
+             +------+--------+\n\
+             | a    |   1.5  |\n\
+             | b    |  12.25 |\n\
+             | c    | 100    |\n\
+             +------+--------+\n"
+        );
+    }
+
+    #[test]
+    fn decimal_points_line_up_after_escaping() {
+        let table = decimal_table(["1|2.5", "3.25", "x\ny.1"]);
+
+        assert_eq!(
+            table.to_string(),
+            "item |     price\n\
+             ---- | --------:\n\
+             a    |   1\\|2.5 \n\
+             b    |      3.25\n\
+             c    | x<br>y.1 \n"
+        );
     }
 }
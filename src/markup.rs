@@ -0,0 +1,165 @@
+use crate::{display_width, write_padded, Alignment, Renderer, TableData};
+
+/// AsciiDoc `|===` tables.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsciiDoc;
+
+impl Renderer for AsciiDoc {
+    fn render(&self, table: &TableData, f: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        let cols: Vec<&str> = table
+            .alignments()
+            .iter()
+            .map(|align| match align {
+                Alignment::None => "1",
+                Alignment::Left => "<",
+                Alignment::Center => "^",
+                Alignment::Right | Alignment::Decimal => ">",
+            })
+            .collect();
+
+        writeln!(f, "[cols=\"{}\",options=\"header\"]", cols.join(","))?;
+        writeln!(f, "|===")?;
+        asciidoc_row(f, table.header())?;
+        writeln!(f)?;
+        for row in table.rows() {
+            asciidoc_row(f, row)?;
+        }
+        writeln!(f, "|===")
+    }
+}
+
+fn asciidoc_row(f: &mut dyn std::fmt::Write, cells: &[String]) -> std::fmt::Result {
+    let cells: Vec<String> = cells
+        .iter()
+        .map(|cell| format!("|{}", cell.replace('|', "\\|").replace('\n', " +\n")))
+        .collect();
+    writeln!(f, "{}", cells.join(" "))
+}
+
+/// Emacs Org-mode tables with `|---+---|` hlines and `<l>`, `<c>`, `<r>`
+/// alignment cookies.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OrgMode;
+
+impl Renderer for OrgMode {
+    fn render(&self, table: &TableData, f: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        let escape = |cell: &str| cell.replace('|', "\\vert{}").replace(['\r', '\n'], " ");
+        let header: Vec<String> = table.header().iter().map(|c| escape(c)).collect();
+        let rows: Vec<Vec<String>> = table
+            .rows()
+            .iter()
+            .map(|row| row.iter().map(|c| escape(c)).collect())
+            .collect();
+
+        let cookies: Vec<String> = table
+            .alignments()
+            .iter()
+            .map(|align| match align {
+                Alignment::None => "",
+                Alignment::Left => "<l>",
+                Alignment::Center => "<c>",
+                Alignment::Right | Alignment::Decimal => "<r>",
+            })
+            .map(String::from)
+            .collect();
+
+        let mut widths: Vec<usize> = cookies.iter().map(|c| c.len().max(1)).collect();
+        for row in std::iter::once(&header).chain(&rows) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = usize::max(*width, display_width(cell));
+            }
+        }
+
+        let line = |f: &mut dyn std::fmt::Write, cells: &[String]| {
+            for (i, cell) in cells.iter().enumerate() {
+                write!(f, "| ")?;
+                write_padded(f, cell, widths[i], table.alignments()[i])?;
+                write!(f, " ")?;
+            }
+            writeln!(f, "|")
+        };
+        let hline = |f: &mut dyn std::fmt::Write| {
+            let dashes: Vec<String> = widths.iter().map(|w| "-".repeat(w + 2)).collect();
+            writeln!(f, "|{}|", dashes.join("+"))
+        };
+
+        line(f, &header)?;
+        hline(f)?;
+        if cookies.iter().any(|c| !c.is_empty()) {
+            line(f, &cookies)?;
+        }
+        for row in &rows {
+            line(f, row)?;
+        }
+        Ok(())
+    }
+}
+
+/// MediaWiki `{| class="wikitable"` tables.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MediaWiki;
+
+impl Renderer for MediaWiki {
+    fn render(&self, table: &TableData, f: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        let cell = |f: &mut dyn std::fmt::Write, marker: &str, scope, align, text: &str| {
+            let mut attributes = Vec::new();
+            if let Some(scope) = scope {
+                attributes.push(format!("scope=\"{}\"", scope));
+            }
+            match align {
+                Alignment::None => {}
+                Alignment::Left => attributes.push("style=\"text-align:left\"".into()),
+                Alignment::Center => attributes.push("style=\"text-align:center\"".into()),
+                Alignment::Right | Alignment::Decimal => {
+                    attributes.push("style=\"text-align:right\"".into())
+                }
+            }
+
+            if attributes.is_empty() {
+                writeln!(f, "{} {}", marker, mediawiki_escape(text))
+            } else {
+                let attributes = attributes.join(" ");
+                writeln!(f, "{} {} | {}", marker, attributes, mediawiki_escape(text))
+            }
+        };
+
+        writeln!(f, "{{| class=\"wikitable\"")?;
+        writeln!(f, "|-")?;
+        for (text, &align) in table.header().iter().zip(table.alignments()) {
+            cell(f, "!", Some("col"), align, text)?;
+        }
+        for row in table.rows() {
+            writeln!(f, "|-")?;
+            for (i, (text, &align)) in row.iter().zip(table.alignments()).enumerate() {
+                if i == 0 {
+                    cell(f, "!", Some("row"), align, text)?;
+                } else {
+                    cell(f, "|", None, align, text)?;
+                }
+            }
+        }
+        writeln!(f, "|}}")
+    }
+}
+
+fn mediawiki_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '|' => escaped.push_str("&#124;"),
+            '!' => escaped.push_str("&#33;"),
+            '{' => escaped.push_str("&#123;"),
+            '}' => escaped.push_str("&#125;"),
+            '[' => escaped.push_str("&#91;"),
+            ']' => escaped.push_str("&#93;"),
+            '\'' => escaped.push_str("&#39;"),
+            '\n' => escaped.push_str("<br />"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,134 @@
+/// How a column presents cells that hold a number. Cells that don't parse as
+/// a finite number are left as they are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum NumberFormat {
+    /// Whatever the cell's `Display` wrote.
+    #[default]
+    None,
+    /// A fixed number of decimal places: `3.14`.
+    Fixed(usize),
+    /// Fixed decimal places with `,` between groups of thousands: `1,234.5`.
+    Thousands(usize),
+    /// Decimal SI prefixes: `1.2k`, `3.4M`.
+    Si(usize),
+    /// Binary prefixes for byte counts: `512.0 B`, `3.4 MiB`.
+    Binary(usize),
+    /// A fraction as a percentage: `0.256` becomes `25.6%`.
+    Percent(usize),
+    /// Scientific notation: `1.23e4`.
+    Scientific(usize),
+}
+
+const SI: [&str; 7] = ["", "k", "M", "G", "T", "P", "E"];
+const BINARY: [&str; 7] = [" B", " KiB", " MiB", " GiB", " TiB", " PiB", " EiB"];
+
+impl NumberFormat {
+    pub(crate) fn apply(self, text: String) -> String {
+        if let Some(integer) = self.integer(text.trim()) {
+            return integer;
+        }
+
+        let value = match text.trim().parse::<f64>() {
+            Ok(value) if value.is_finite() && self != Self::None => value,
+            _ => return text,
+        };
+
+        match self {
+            Self::None => text,
+            Self::Fixed(decimals) => format!("{:.*}", decimals, value),
+            Self::Thousands(decimals) => group_thousands(&format!("{:.*}", decimals, value)),
+            Self::Si(decimals) => scaled(value, decimals, 1000.0, &SI),
+            Self::Binary(decimals) => scaled(value, decimals, 1024.0, &BINARY),
+            Self::Percent(decimals) => format!("{:.*}%", decimals, value * 100.0),
+            Self::Scientific(decimals) => format!("{:.*e}", decimals, value),
+        }
+    }
+
+    /// Writes an integer for `Fixed` and `Thousands` from its digits, since
+    /// going through `f64` would round anything above 2^53.
+    fn integer(self, text: &str) -> Option<String> {
+        let decimals = match self {
+            Self::Fixed(decimals) | Self::Thousands(decimals) => decimals,
+            _ => return None,
+        };
+        let integer = match text.parse::<i128>() {
+            Ok(integer) => integer.to_string(),
+            Err(_) => text.parse::<u128>().ok()?.to_string(),
+        };
+
+        let fixed = match decimals {
+            0 => integer,
+            decimals => format!("{}.{}", integer, "0".repeat(decimals)),
+        };
+        Some(match self {
+            Self::Thousands(_) => group_thousands(&fixed),
+            _ => fixed,
+        })
+    }
+}
+
+fn group_thousands(number: &str) -> String {
+    let (sign, number) = match number.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", number),
+    };
+    let (integer, fraction) = match number.find('.') {
+        Some(dot) => number.split_at(dot),
+        None => (number, ""),
+    };
+
+    let mut grouped = String::with_capacity(number.len() + number.len() / 3 + 1);
+    grouped.push_str(sign);
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped.push_str(fraction);
+    grouped
+}
+
+/// Picks the largest unit that keeps the value at or above one, moving up
+/// once more if rounding would print a full `base`.
+fn scaled(value: f64, decimals: usize, base: f64, units: &[&str]) -> String {
+    let mut scaled = value;
+    let mut unit = 0;
+    while unit + 1 < units.len() {
+        let rounded: f64 = format!("{:.*}", decimals, scaled.abs())
+            .parse()
+            .unwrap_or_default();
+        if rounded < base {
+            break;
+        }
+        scaled /= base;
+        unit += 1;
+    }
+    format!("{:.*}{}", decimals, scaled, units[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NumberFormat;
+
+    #[test]
+    fn large_integers_keep_their_digits() {
+        let text = || String::from("12345678901234567891");
+        assert_eq!(NumberFormat::Fixed(0).apply(text()), "12345678901234567891");
+        assert_eq!(
+            NumberFormat::Fixed(2).apply(text()),
+            "12345678901234567891.00"
+        );
+        assert_eq!(
+            NumberFormat::Thousands(0).apply(text()),
+            "12,345,678,901,234,567,891"
+        );
+        assert_eq!(NumberFormat::Thousands(1).apply("-1234".into()), "-1,234.0");
+        assert_eq!(NumberFormat::Fixed(1).apply("2.25".into()), "2.2");
+    }
+}
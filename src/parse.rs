@@ -0,0 +1,315 @@
+use std::str::FromStr;
+
+use crate::{
+    Alignment, Builder, DynBuilder, DynRow, DynTable, Error, ErrorKind, Row, Style, Table,
+};
+
+/// A pipe table as it appears in the source, before its column count is
+/// checked against a `Table`'s `WIDTH`.
+#[derive(Debug, Clone)]
+struct RawTable {
+    header: Vec<String>,
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<String>>,
+    style: Style,
+}
+
+/// Parses the lines of a table that starts at its header row and continues
+/// to the last line given.
+pub(crate) fn parse_lines<'l>(
+    lines: impl IntoIterator<Item = &'l str>,
+) -> Result<DynTable<String, String, String>, Error> {
+    RawTable::parse(lines, 1).map(RawTable::into_dyn_table)
+}
+
+impl RawTable {
+    /// `first_line` is the line number of the header row, for error reporting.
+    fn parse<'l>(
+        lines: impl IntoIterator<Item = &'l str>,
+        first_line: usize,
+    ) -> Result<Self, Error> {
+        let mut lines = lines.into_iter();
+
+        let header_line = lines.next().unwrap_or_default();
+        let header = split_row(header_line)
+            .ok_or_else(|| Error::new(ErrorKind::MissingHeader, header_line).at(first_line, 1))?;
+
+        let delimiter_line = lines.next().unwrap_or_default();
+        let alignments = parse_alignments(delimiter_line, first_line + 1)?;
+        if alignments.len() != header.len() {
+            let kind = ErrorKind::ColumnCount {
+                expected: header.len(),
+                found: alignments.len(),
+            };
+            return Err(Error::new(kind, delimiter_line).at(first_line + 1, 1));
+        }
+
+        let rows = lines
+            .map(|line| {
+                let mut row = split_cells(line);
+                row.resize(header.len(), String::new());
+                row
+            })
+            .collect();
+
+        let style = if header_line.trim_start().starts_with('|') {
+            Style::Github
+        } else {
+            Style::Bare
+        };
+
+        Ok(Self {
+            header,
+            alignments,
+            rows,
+            style,
+        })
+    }
+
+    fn into_dyn_table(self) -> DynTable<String, String, String> {
+        let mut builder = DynBuilder::new();
+        builder.style(self.style);
+        builder.header(into_dyn_row(self.header));
+        builder.alignments(into_dyn_row(self.alignments));
+        for row in self.rows {
+            builder.row(into_dyn_row(row));
+        }
+        builder.finish()
+    }
+
+    /// Parses a string that holds nothing but a single table, optionally
+    /// surrounded by blank lines.
+    fn parse_exact(s: &str) -> Result<Self, Error> {
+        let lines: Vec<&str> = s.lines().collect();
+        let start = lines
+            .iter()
+            .position(|line| !line.trim().is_empty())
+            .unwrap_or(lines.len());
+        let end = lines
+            .iter()
+            .rposition(|line| !line.trim().is_empty())
+            .map_or(start, |last| last + 1);
+
+        if let Some(blank) = (start..end).find(|&i| lines[i].trim().is_empty()) {
+            return Err(Error::new(ErrorKind::BlankLine, lines[blank]).at(blank + 1, 1));
+        }
+
+        Self::parse(lines[start..end].iter().copied(), start + 1)
+    }
+
+    fn check_width(&self, width: usize) -> Result<(), Error> {
+        if self.header.len() == width {
+            return Ok(());
+        }
+
+        let kind = ErrorKind::ColumnCount {
+            expected: width,
+            found: self.header.len(),
+        };
+        Err(Error::new(kind, self.header.join(" | ")))
+    }
+}
+
+/// Splits a row at unescaped pipes, or returns `None` if the line has none
+/// and therefore can't start a table.
+pub(crate) fn split_row(line: &str) -> Option<Vec<String>> {
+    unescaped_pipes(line).next()?;
+    Some(split_cells(line))
+}
+
+pub(crate) fn parse_delimiter_row(line: &str) -> Option<Vec<Alignment>> {
+    parse_alignments(line, 1).ok()
+}
+
+fn parse_alignments(line: &str, line_number: usize) -> Result<Vec<Alignment>, Error> {
+    if unescaped_pipes(line).next().is_none() {
+        return Err(Error::new(ErrorKind::MissingDelimiterRow, line).at(line_number, 1));
+    }
+
+    cells(line)
+        .into_iter()
+        .map(|(offset, cell)| {
+            cell.parse::<Alignment>().map_err(|error| {
+                let column = line[..offset].chars().count() + 1;
+                error.at(line_number, column)
+            })
+        })
+        .collect()
+}
+
+fn split_cells(line: &str) -> Vec<String> {
+    cells(line)
+        .into_iter()
+        .map(|(_, cell)| cell.replace("\\|", "|"))
+        .collect()
+}
+
+/// The trimmed, still escaped contents of each cell together with their byte
+/// offset into `line`.
+fn cells(line: &str) -> Vec<(usize, &str)> {
+    let indent = line.len() - line.trim_start().len();
+    let line = line.trim();
+    let mut bounds: Vec<usize> = unescaped_pipes(line).collect();
+
+    let start = match bounds.first() {
+        Some(0) => bounds.remove(0) + 1,
+        _ => 0,
+    };
+    let end = match bounds.last() {
+        Some(&last) if last + 1 == line.len() && last + 1 > start => {
+            bounds.pop();
+            last
+        }
+        _ => line.len(),
+    };
+
+    let cell = |from: usize, to: usize| {
+        let raw = &line[from..to];
+        let leading = raw.len() - raw.trim_start().len();
+        (indent + from + leading, raw.trim())
+    };
+
+    let mut cells = Vec::with_capacity(bounds.len() + 1);
+    let mut from = start;
+    for pipe in bounds {
+        cells.push(cell(from, pipe));
+        from = pipe + 1;
+    }
+    cells.push(cell(from, end));
+    cells
+}
+
+fn unescaped_pipes(line: &str) -> impl Iterator<Item = usize> + '_ {
+    let bytes = line.as_bytes();
+    bytes
+        .iter()
+        .enumerate()
+        .filter(move |&(i, &b)| b == b'|' && (i == 0 || bytes[i - 1] != b'\\'))
+        .map(|(i, _)| i)
+}
+
+fn into_row<T, const WIDTH: usize>(cells: Vec<T>) -> Row<T, T, WIDTH> {
+    Row::from_cells(cells).expect("rows are padded to the header, which was checked")
+}
+
+fn into_dyn_row<T>(cells: Vec<T>) -> DynRow<T, T> {
+    DynRow::from_cells(cells).expect("a row that has a pipe has a cell")
+}
+
+impl<const WIDTH: usize> FromStr for Table<String, String, String, WIDTH> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let raw = RawTable::parse_exact(s)?;
+        raw.check_width(WIDTH + 1)?;
+
+        let mut builder = Builder::new();
+        builder.style(raw.style);
+        builder.header(into_row(raw.header));
+        builder.alignments(into_row(raw.alignments));
+        for row in raw.rows {
+            builder.row(into_row(row));
+        }
+
+        Ok(builder.finish())
+    }
+}
+
+impl FromStr for DynTable<String, String, String> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RawTable::parse_exact(s).map(RawTable::into_dyn_table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Alignment, DynTable, ErrorKind, Position, Table};
+
+    #[test]
+    fn parses_with_and_without_outer_pipes() {
+        let outer: Table<String, String, String, 1> =
+            "| a | b |\n|---|---|\n| 1 | 2 |".parse().unwrap();
+        let bare: Table<String, String, String, 1> = "a | b\n---|---\n1 | 2".parse().unwrap();
+
+        for table in [outer, bare] {
+            assert_eq!(table.header.header, "a");
+            assert_eq!(table.header.content, ["b"]);
+            assert_eq!(table.content[0].header, "1");
+            assert_eq!(table.content[0].content, ["2"]);
+        }
+    }
+
+    #[test]
+    fn unescapes_pipes_inside_cells() {
+        let table: DynTable<String, String, String> = "a | b\n--|--\nx\\|y | 1".parse().unwrap();
+        assert_eq!(table.content[0].header(), "x|y");
+        assert_eq!(table.content[0].content(), ["1"]);
+    }
+
+    #[test]
+    fn pads_short_rows() {
+        let table: DynTable<String, String, String> =
+            "a | b | c\n--|--|--\n1\n2 | 3".parse().unwrap();
+        assert_eq!(table.content[0].content(), ["", ""]);
+        assert_eq!(table.content[1].content(), ["3", ""]);
+    }
+
+    #[test]
+    fn alignments_accept_any_dash_count() {
+        let table: Table<String, String, String, 3> =
+            "a | b | c | d\n- | :- | ---------: | :-:".parse().unwrap();
+        assert_eq!(table.alignments.header, Alignment::None);
+        assert_eq!(
+            table.alignments.content,
+            [Alignment::Left, Alignment::Right, Alignment::Center]
+        );
+
+        for invalid in ["", ":", "::", "-:-", "--x"] {
+            assert!(invalid.parse::<Alignment>().is_err(), "{:?}", invalid);
+        }
+    }
+
+    #[test]
+    fn checks_column_count_against_width() {
+        let error = "a | b | c\n--|--|--"
+            .parse::<Table<String, String, String, 1>>()
+            .unwrap_err();
+        assert_eq!(
+            error.kind(),
+            ErrorKind::ColumnCount {
+                expected: 2,
+                found: 3
+            }
+        );
+    }
+
+    #[test]
+    fn errors_point_at_line_and_column() {
+        let error = "\n| a | b |\n|---|-x-|"
+            .parse::<DynTable<String, String, String>>()
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidAlignment);
+        assert_eq!(error.input(), "-x-");
+        assert_eq!(error.position(), Some(Position { line: 3, column: 6 }));
+
+        let error = "| a | b |\n|---|"
+            .parse::<DynTable<String, String, String>>()
+            .unwrap_err();
+        assert_eq!(
+            error.kind(),
+            ErrorKind::ColumnCount {
+                expected: 2,
+                found: 1
+            }
+        );
+        assert_eq!(error.position(), Some(Position { line: 2, column: 1 }));
+
+        let error = "a | b\n\n--|--"
+            .parse::<DynTable<String, String, String>>()
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::BlankLine);
+        assert_eq!(error.position(), Some(Position { line: 2, column: 1 }));
+    }
+}
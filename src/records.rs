@@ -0,0 +1,550 @@
+use std::fmt::Display;
+
+use serde::{
+    ser::{self, Impossible, SerializeMap, SerializeStruct},
+    Serialize, Serializer,
+};
+
+use crate::{DynBuilder, DynRow, Error, ErrorKind};
+
+impl DynBuilder<String, String, String> {
+    /// Lays out structs or maps as rows, one column per field. Field names
+    /// become the header and the first field becomes the row header.
+    pub fn from_records<I>(records: I) -> Result<Self, Error>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+    {
+        let mut records = records.into_iter();
+        let mut builder = Self::new();
+
+        let first = records
+            .next()
+            .ok_or_else(|| Error::new(ErrorKind::MissingHeader, ""))?
+            .serialize(Record)?;
+        let (names, cells): (Vec<_>, Vec<_>) = first.into_iter().unzip();
+        builder.header(into_dyn_row(names.clone())?);
+        builder.row(into_dyn_row(cells)?);
+
+        for record in records {
+            let (fields, cells): (Vec<_>, Vec<_>) = record.serialize(Record)?.into_iter().unzip();
+            if fields.len() != names.len() {
+                let kind = ErrorKind::ColumnCount {
+                    expected: names.len(),
+                    found: fields.len(),
+                };
+                return Err(Error::new(kind, fields.join(", ")));
+            }
+            if fields != names {
+                let kind = ErrorKind::Unrepresentable {
+                    reason: "records have different fields",
+                };
+                return Err(Error::new(kind, fields.join(", ")));
+            }
+            builder.row(into_dyn_row(cells)?);
+        }
+
+        Ok(builder)
+    }
+}
+
+fn into_dyn_row(cells: Vec<String>) -> Result<DynRow<String, String>, Error> {
+    match DynRow::from_cells(cells) {
+        Some(row) => Ok(row),
+        None => unrepresentable("records need at least one field"),
+    }
+}
+
+fn unrepresentable<T>(reason: &'static str) -> Result<T, Error> {
+    Err(Error::new(ErrorKind::Unrepresentable { reason }, ""))
+}
+
+impl ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        let kind = ErrorKind::Unrepresentable {
+            reason: "the record failed to serialize",
+        };
+        Error::new(kind, msg.to_string())
+    }
+}
+
+/// Serializes a struct or map into its field names and stringified values,
+/// in field order.
+struct Record;
+
+struct Fields {
+    fields: Vec<(String, String)>,
+    key: Option<String>,
+}
+
+impl Fields {
+    fn new(len: Option<usize>) -> Self {
+        Self {
+            fields: Vec::with_capacity(len.unwrap_or_default()),
+            key: None,
+        }
+    }
+
+    fn push<T: Serialize + ?Sized>(&mut self, name: String, value: &T) -> Result<(), Error> {
+        let cell = value.serialize(Cell).map_err(|error| match error.kind() {
+            kind @ ErrorKind::Unrepresentable { .. } if error.input().is_empty() => {
+                Error::new(kind, name.as_str())
+            }
+            _ => error,
+        })?;
+        self.fields.push((name, cell));
+        Ok(())
+    }
+}
+
+impl Serializer for Record {
+    type Ok = Vec<(String, String)>;
+    type Error = Error;
+    type SerializeSeq = Impossible<Self::Ok, Error>;
+    type SerializeTuple = Impossible<Self::Ok, Error>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Error>;
+    type SerializeMap = Fields;
+    type SerializeStruct = Fields;
+    type SerializeStructVariant = Impossible<Self::Ok, Error>;
+
+    fn serialize_bool(self, _: bool) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_i8(self, _: i8) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_i16(self, _: i16) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_i32(self, _: i32) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_i64(self, _: i64) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_u8(self, _: u8) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_u16(self, _: u16) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_u32(self, _: u32) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_u64(self, _: u64) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_f32(self, _: f32) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_f64(self, _: f64) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_char(self, _: char) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_str(self, _: &str) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, _: &T) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Self::Ok, Error> {
+        not_a_record()
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        not_a_record()
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Error> {
+        not_a_record()
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        not_a_record()
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        not_a_record()
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        Ok(Fields::new(len))
+    }
+
+    fn serialize_struct(self, _: &'static str, len: usize) -> Result<Self::SerializeStruct, Error> {
+        Ok(Fields::new(Some(len)))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        not_a_record()
+    }
+}
+
+fn not_a_record<T>() -> Result<T, Error> {
+    unrepresentable("records must be structs or maps")
+}
+
+impl SerializeStruct for Fields {
+    type Ok = Vec<(String, String)>;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        Ok(self.fields)
+    }
+}
+
+impl SerializeMap for Fields {
+    type Ok = Vec<(String, String)>;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(key.serialize(Cell)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self.key.take().unwrap_or_default();
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        Ok(self.fields)
+    }
+}
+
+/// Serializes a single field value into the text of its cell. `None` and
+/// unit values leave the cell empty.
+struct Cell;
+
+impl Serializer for Cell {
+    type Ok = String;
+    type Error = Error;
+    type SerializeSeq = Impossible<String, Error>;
+    type SerializeTuple = Impossible<String, Error>;
+    type SerializeTupleStruct = Impossible<String, Error>;
+    type SerializeTupleVariant = Impossible<String, Error>;
+    type SerializeMap = Impossible<String, Error>;
+    type SerializeStruct = Impossible<String, Error>;
+    type SerializeStructVariant = Impossible<String, Error>;
+
+    fn serialize_bool(self, v: bool) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_char(self, v: char) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<String, Error> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<String, Error> {
+        Ok(String::from_utf8_lossy(v).into_owned())
+    }
+
+    fn serialize_none(self) -> Result<String, Error> {
+        Ok(String::new())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<String, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<String, Error> {
+        Ok(String::new())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<String, Error> {
+        Ok(String::new())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<String, Error> {
+        Ok(variant.to_string())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<String, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        value: &T,
+    ) -> Result<String, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        nested()
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Error> {
+        nested()
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        nested()
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        nested()
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        nested()
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self::SerializeStruct, Error> {
+        nested()
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        nested()
+    }
+}
+
+fn nested<T>() -> Result<T, Error> {
+    unrepresentable("nested values can't be written to a single cell")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde::Serialize;
+
+    use crate::{DynBuilder, DynTable, ErrorKind};
+
+    #[derive(Serialize)]
+    struct File {
+        path: &'static str,
+        size: u64,
+        owner: Option<&'static str>,
+        marker: (),
+    }
+
+    #[derive(Serialize)]
+    struct Nested {
+        name: &'static str,
+        tags: Vec<&'static str>,
+    }
+
+    #[test]
+    fn fields_become_columns_in_order() {
+        let files = [
+            File {
+                path: "a.txt",
+                size: 12,
+                owner: Some("root"),
+                marker: (),
+            },
+            File {
+                path: "b.txt",
+                size: 3,
+                owner: None,
+                marker: (),
+            },
+        ];
+        let table: DynTable<String, String, String> =
+            DynBuilder::from_records(&files).unwrap().finish();
+
+        assert_eq!(
+            table.to_string(),
+            "path  | size | owner | marker\n\
+             ----- | ---: | ----: | -----:\n\
+             a.txt |   12 |  root |       \n\
+             b.txt |    3 |       |       \n"
+        );
+    }
+
+    #[test]
+    fn nested_values_are_rejected() {
+        let records = [Nested {
+            name: "a",
+            tags: vec!["x"],
+        }];
+        let error = DynBuilder::from_records(records).unwrap_err();
+        assert!(matches!(error.kind(), ErrorKind::Unrepresentable { .. }));
+    }
+
+    #[test]
+    fn records_must_share_fields() {
+        let first = BTreeMap::from([("a", 1), ("b", 2)]);
+        let renamed = BTreeMap::from([("a", 1), ("c", 2)]);
+        let longer = BTreeMap::from([("a", 1), ("b", 2), ("c", 3)]);
+
+        let error = DynBuilder::from_records([&first, &renamed]).unwrap_err();
+        assert!(matches!(error.kind(), ErrorKind::Unrepresentable { .. }));
+
+        let error = DynBuilder::from_records([&first, &longer]).unwrap_err();
+        assert_eq!(
+            error.kind(),
+            ErrorKind::ColumnCount {
+                expected: 2,
+                found: 3
+            }
+        );
+    }
+
+    #[test]
+    fn empty_input_has_no_header() {
+        let error = DynBuilder::from_records(Vec::<File>::new()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::MissingHeader);
+    }
+}
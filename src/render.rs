@@ -0,0 +1,142 @@
+use std::{borrow::Cow, fmt::Display};
+
+use crate::{write_padded, Alignment, Decimals, EscapePolicy, Format, Style, View};
+
+/// An output format for tables. Implement it to render tables in formats
+/// this crate doesn't ship with.
+pub trait Renderer {
+    fn render(&self, table: &TableData, out: &mut dyn std::fmt::Write) -> std::fmt::Result;
+}
+
+/// The cells of a table as handed to a `Renderer`. Every row, including the
+/// header, starts with its row header.
+#[derive(Debug, Clone)]
+pub struct TableData {
+    header: Vec<String>,
+    alignments: Vec<Alignment>,
+    widths: Vec<usize>,
+    rows: Vec<Vec<String>>,
+    decimals: Vec<Option<Decimals>>,
+    format: Format,
+}
+
+impl TableData {
+    pub(crate) fn new<LH, TH, T>(view: &View<'_, LH, TH, T>) -> Self
+    where
+        LH: Display,
+        TH: Display,
+        T: Display,
+    {
+        Self {
+            header: view.header.texts().collect(),
+            alignments: view.column_alignments().collect(),
+            widths: view.widths.clone(),
+            rows: view
+                .rows
+                .iter()
+                .map(|row| row.formatted(view.formats).collect())
+                .collect(),
+            decimals: view.decimals.clone(),
+            format: view.format,
+        }
+    }
+
+    pub fn header(&self) -> &[String] {
+        &self.header
+    }
+
+    pub fn alignments(&self) -> &[Alignment] {
+        &self.alignments
+    }
+
+    /// Display width of every column once its cells are escaped for
+    /// markdown, never less than three.
+    pub fn widths(&self) -> &[usize] {
+        &self.widths
+    }
+
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+
+    /// Pads `text` from a body row so its decimal point lines up with the
+    /// rest of an `Alignment::Decimal` column. Columns are laid out for cells
+    /// escaped with `escape()`, so pass `text` escaped. Other columns get
+    /// `text` back as it is.
+    pub fn align_decimal<'a>(&self, column: usize, text: &'a str) -> Cow<'a, str> {
+        match self.decimals.get(column).copied().flatten() {
+            Some(decimals) => Cow::Owned(decimals.align(text)),
+            None => Cow::Borrowed(text),
+        }
+    }
+
+    pub fn style(&self) -> Style {
+        self.format.style
+    }
+
+    pub fn escape(&self) -> EscapePolicy {
+        self.format.escape
+    }
+}
+
+/// A table paired with a `Renderer`, created by `Table::render`.
+pub struct Rendered<R> {
+    table: TableData,
+    renderer: R,
+}
+
+impl<R> Rendered<R> {
+    pub(crate) fn new(table: TableData, renderer: R) -> Self {
+        Self { table, renderer }
+    }
+}
+
+impl<R: Renderer> Display for Rendered<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.renderer.render(&self.table, f)
+    }
+}
+
+/// Markdown pipe tables in the table's `Style`, which is what `Display`
+/// writes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Markdown;
+
+impl Renderer for Markdown {
+    fn render(&self, table: &TableData, out: &mut dyn std::fmt::Write) -> std::fmt::Result {
+        let format = table.format;
+        let columns = table.widths.len() - 1;
+
+        let row = |out: &mut dyn std::fmt::Write, cells: &[String], body: bool| {
+            format.style.write_row(out, columns, |out, i| {
+                let text = format.escape.apply(&cells[i]);
+                if !format.style.pads() {
+                    return write!(out, "{}", text);
+                }
+
+                let aligned = if body {
+                    table.align_decimal(i, &text)
+                } else {
+                    Cow::Borrowed(&*text)
+                };
+                write_padded(out, &aligned, table.widths[i], table.alignments[i])
+            })?;
+            writeln!(out)
+        };
+
+        row(out, &table.header, false)?;
+        format.style.write_row(out, columns, |out, i| {
+            if format.style.pads() {
+                write!(out, "{}", table.alignments[i].separator(table.widths[i]))
+            } else {
+                write!(out, "{}", table.alignments[i].as_ref())
+            }
+        })?;
+        writeln!(out)?;
+        for cells in &table.rows {
+            row(out, cells, true)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+use std::{fmt::Display, iter};
+
+use crate::{line_widths, lines, write_padded, Error, ErrorKind, View};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Layout {
+    Grid,
+    Simple,
+}
+
+/// Renders a table as a reStructuredText grid or simple table, created by
+/// `Table::rst_grid` and `Table::rst_simple`.
+pub struct Rst<'t, LH, TH, T> {
+    view: View<'t, LH, TH, T>,
+    layout: Layout,
+}
+
+impl<'t, LH, TH, T> Rst<'t, LH, TH, T>
+where
+    LH: Display,
+    TH: Display,
+    T: Display,
+{
+    /// Cells with line breaks span several lines of their row.
+    pub(crate) fn grid(view: View<'t, LH, TH, T>) -> Result<Self, Error> {
+        Ok(Self {
+            view,
+            layout: Layout::Grid,
+        })
+    }
+
+    pub(crate) fn simple(view: View<'t, LH, TH, T>) -> Result<Self, Error> {
+        view.header
+            .texts()
+            .try_for_each(|text| check_simple_cell(&text))?;
+        for row in &view.rows {
+            row.formatted(view.formats)
+                .try_for_each(|text| check_simple_cell(&text))?;
+
+            // An empty first column marks a continuation of the previous row.
+            if row.header.to_string().trim().is_empty() {
+                let reason = "an empty first column continues the previous row";
+                return Err(Error::new(ErrorKind::Unrepresentable { reason }, ""));
+            }
+        }
+
+        Ok(Self {
+            view,
+            layout: Layout::Simple,
+        })
+    }
+
+    fn rule(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        widths: &[usize],
+        fill: char,
+    ) -> std::fmt::Result {
+        let (left, cross, right, padding) = match self.layout {
+            Layout::Grid => ("+", "+", "+", 2),
+            Layout::Simple => ("", "  ", "", 0),
+        };
+
+        write!(f, "{}", left)?;
+        for (i, width) in widths.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", cross)?;
+            }
+            for _ in 0..width + padding {
+                write!(f, "{}", fill)?;
+            }
+        }
+        writeln!(f, "{}", right)
+    }
+
+    fn row(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        widths: &[usize],
+        cells: &[Vec<&str>],
+    ) -> std::fmt::Result {
+        let (left, separator, right) = match self.layout {
+            Layout::Grid => ("| ", " | ", " |"),
+            Layout::Simple => ("", "  ", ""),
+        };
+        let height = cells.iter().map(Vec::len).max().unwrap_or(1);
+
+        for line in 0..height {
+            write!(f, "{}", left)?;
+            for (i, cell) in cells.iter().enumerate() {
+                if i > 0 {
+                    write!(f, "{}", separator)?;
+                }
+                let text = cell.get(line).copied().unwrap_or_default();
+                write_padded(f, text, widths[i], self.view.alignments[i])?;
+            }
+            writeln!(f, "{}", right)?;
+        }
+        Ok(())
+    }
+}
+
+impl<LH, TH, T> Display for Rst<'_, LH, TH, T>
+where
+    LH: Display,
+    TH: Display,
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let header: Vec<String> = self.view.header.texts().collect();
+        let rows: Vec<Vec<String>> = self
+            .view
+            .rows
+            .iter()
+            .map(|&row| self.view.padded(row).collect())
+            .collect();
+
+        let header: Vec<Vec<&str>> = header.iter().map(|text| lines(text)).collect();
+        let rows: Vec<Vec<Vec<&str>>> = rows
+            .iter()
+            .map(|row| row.iter().map(|text| lines(text)).collect())
+            .collect();
+
+        let widths =
+            line_widths(iter::once(header.as_slice()).chain(rows.iter().map(Vec::as_slice)));
+
+        match self.layout {
+            Layout::Grid => {
+                self.rule(f, &widths, '-')?;
+                self.row(f, &widths, &header)?;
+                self.rule(f, &widths, '=')?;
+                for row in &rows {
+                    self.row(f, &widths, row)?;
+                    self.rule(f, &widths, '-')?;
+                }
+                if rows.is_empty() {
+                    self.rule(f, &widths, '-')?;
+                }
+                Ok(())
+            }
+            Layout::Simple => {
+                self.rule(f, &widths, '=')?;
+                self.row(f, &widths, &header)?;
+                self.rule(f, &widths, '=')?;
+                for row in &rows {
+                    self.row(f, &widths, row)?;
+                }
+                self.rule(f, &widths, '=')
+            }
+        }
+    }
+}
+
+fn check_line_breaks(text: &str) -> Result<(), Error> {
+    if text.contains(['\r', '\n']) {
+        let reason = "cells can't contain line breaks";
+        return Err(Error::new(ErrorKind::Unrepresentable { reason }, text));
+    }
+    Ok(())
+}
+
+fn check_simple_cell(text: &str) -> Result<(), Error> {
+    check_line_breaks(text)?;
+
+    let trimmed = text.trim();
+    if !trimmed.is_empty() && trimmed.chars().all(|c| c == '=' || c == '-') {
+        let reason = "a cell of only `=` or `-` reads as a table border";
+        return Err(Error::new(ErrorKind::Unrepresentable { reason }, text));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Builder;
+
+    #[test]
+    fn grid_cells_span_lines_and_stay_unescaped() {
+        let mut builder: Builder<&str, &str, &str, 1> = Builder::new();
+        builder.header(("key", ["value"]));
+        builder.row(("x", ["y|z"]));
+        builder.row(("two\nlines", ["1"]));
+
+        assert_eq!(
+            builder.finish().rst_grid().unwrap().to_string(),
+            "+-------+-------+\n\
+             | key   | value |\n\
+             +=======+=======+\n\
+             | x     |   y|z |\n\
+             +-------+-------+\n\
+             | two   |     1 |\n\
+             | lines |       |\n\
+             +-------+-------+\n"
+        );
+    }
+
+    #[test]
+    fn simple_tables_reject_line_breaks() {
+        let mut builder: Builder<&str, &str, &str, 1> = Builder::new();
+        builder.header(("key", ["value"]));
+        builder.row(("two\nlines", ["1"]));
+        assert!(builder.finish().rst_simple().is_err());
+    }
+}
@@ -0,0 +1,284 @@
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::{SerializeSeq, SerializeStruct},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    Alignment, Builder, DynBuilder, DynRow, DynTable, EscapePolicy, NumberFormat, Row, RowView,
+    Style, Table, View,
+};
+
+/// Rows are sequences of cells, starting with the row header.
+impl<H: Serialize, C: Serialize> Serialize for RowView<'_, H, C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.content.len() + 1))?;
+        seq.serialize_element(self.header)?;
+        for cell in self.content {
+            seq.serialize_element(cell)?;
+        }
+        seq.end()
+    }
+}
+
+impl<LH, TH, T> Serialize for View<'_, LH, TH, T>
+where
+    LH: Serialize,
+    TH: Serialize,
+    T: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Table", 6)?;
+        state.serialize_field("header", &self.header)?;
+        state.serialize_field("alignments", &self.alignments)?;
+        match &self.formats {
+            Some(formats) => state.serialize_field("formats", formats)?,
+            None => state.skip_field("formats")?,
+        }
+        state.serialize_field("style", &self.format.style)?;
+        state.serialize_field("escape", &self.format.escape)?;
+        state.serialize_field("rows", &self.rows)?;
+        state.end()
+    }
+}
+
+impl<F: Serialize, R: Serialize, const WIDTH: usize> Serialize for Row<F, R, WIDTH> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.view().serialize(serializer)
+    }
+}
+
+impl<F: Serialize, R: Serialize> Serialize for DynRow<F, R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.view().serialize(serializer)
+    }
+}
+
+impl<LH, TH, T, const WIDTH: usize> Serialize for Table<LH, TH, T, WIDTH>
+where
+    LH: fmt::Display + Serialize,
+    TH: fmt::Display + Serialize,
+    T: fmt::Display + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.view().serialize(serializer)
+    }
+}
+
+impl<LH, TH, T> Serialize for DynTable<LH, TH, T>
+where
+    LH: fmt::Display + Serialize,
+    TH: fmt::Display + Serialize,
+    T: fmt::Display + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.view().serialize(serializer)
+    }
+}
+
+/// Reads a row header followed by any number of cells.
+struct RowVisitor<F, R>(PhantomData<(F, R)>);
+
+impl<'de, F: Deserialize<'de>, R: Deserialize<'de>> Visitor<'de> for RowVisitor<F, R> {
+    type Value = (F, Vec<R>);
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a sequence of cells starting with the row header")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let header = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let mut content = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(cell) = seq.next_element()? {
+            content.push(cell);
+        }
+        Ok((header, content))
+    }
+}
+
+impl<'de, F, R, const WIDTH: usize> Deserialize<'de> for Row<F, R, WIDTH>
+where
+    F: Deserialize<'de>,
+    R: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (header, content) = deserializer.deserialize_seq(RowVisitor(PhantomData))?;
+        let found = content.len() + 1;
+        let content = content.try_into().map_err(|_| {
+            let expected = format!("a row of {} cells", WIDTH + 1);
+            de::Error::invalid_length(found, &expected.as_str())
+        })?;
+        Ok(Self { header, content })
+    }
+}
+
+impl<'de, F, R> Deserialize<'de> for DynRow<F, R>
+where
+    F: Deserialize<'de>,
+    R: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_seq(RowVisitor(PhantomData))
+            .map(Self::from)
+    }
+}
+
+/// The fields of a serialized table. Everything but the header may be left
+/// out.
+#[derive(Deserialize)]
+struct Parts<H, A, F, R> {
+    header: H,
+    alignments: Option<A>,
+    formats: Option<F>,
+    style: Option<Style>,
+    escape: Option<EscapePolicy>,
+    #[serde(default = "Vec::new")]
+    rows: Vec<R>,
+}
+
+type TableParts<LH, TH, T, const WIDTH: usize> = Parts<
+    Row<LH, TH, WIDTH>,
+    Row<Alignment, Alignment, WIDTH>,
+    Row<NumberFormat, NumberFormat, WIDTH>,
+    Row<LH, T, WIDTH>,
+>;
+
+type DynTableParts<LH, TH, T> = Parts<
+    DynRow<LH, TH>,
+    DynRow<Alignment, Alignment>,
+    DynRow<NumberFormat, NumberFormat>,
+    DynRow<LH, T>,
+>;
+
+impl<'de, LH, TH, T, const WIDTH: usize> Deserialize<'de> for Table<LH, TH, T, WIDTH>
+where
+    LH: fmt::Display + Deserialize<'de>,
+    TH: fmt::Display + Deserialize<'de>,
+    T: fmt::Display + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let parts = TableParts::<LH, TH, T, WIDTH>::deserialize(deserializer)?;
+
+        let mut builder = Builder::new();
+        if let Some(style) = parts.style {
+            builder.style(style);
+        }
+        if let Some(escape) = parts.escape {
+            builder.try_escape(escape).map_err(de::Error::custom)?;
+        }
+        builder
+            .try_header(parts.header)
+            .map_err(de::Error::custom)?;
+        if let Some(alignments) = parts.alignments {
+            builder
+                .try_alignments(alignments)
+                .map_err(de::Error::custom)?;
+        }
+        if let Some(formats) = parts.formats {
+            builder.try_formats(formats).map_err(de::Error::custom)?;
+        }
+        for row in parts.rows {
+            builder.try_row(row).map_err(de::Error::custom)?;
+        }
+        builder.try_finish().map_err(de::Error::custom)
+    }
+}
+
+impl<'de, LH, TH, T> Deserialize<'de> for DynTable<LH, TH, T>
+where
+    LH: fmt::Display + Deserialize<'de>,
+    TH: fmt::Display + Deserialize<'de>,
+    T: fmt::Display + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let parts = DynTableParts::<LH, TH, T>::deserialize(deserializer)?;
+
+        let mut builder = DynBuilder::new();
+        if let Some(style) = parts.style {
+            builder.style(style);
+        }
+        if let Some(escape) = parts.escape {
+            builder.try_escape(escape).map_err(de::Error::custom)?;
+        }
+        builder
+            .try_header(parts.header)
+            .map_err(de::Error::custom)?;
+        if let Some(alignments) = parts.alignments {
+            builder
+                .try_alignments(alignments)
+                .map_err(de::Error::custom)?;
+        }
+        if let Some(formats) = parts.formats {
+            builder.try_formats(formats).map_err(de::Error::custom)?;
+        }
+        for row in parts.rows {
+            builder.try_row(row).map_err(de::Error::custom)?;
+        }
+        builder.try_finish().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Alignment, Builder, DynTable, EscapePolicy, NumberFormat, Style, Table};
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut builder: Builder<String, String, String, 2> = Builder::new();
+        builder.style(Style::Github);
+        builder.escape(EscapePolicy::Reject);
+        builder.header(("name".into(), ["share".into(), "size".into()]));
+        builder.alignments((Alignment::Left, [Alignment::Decimal, Alignment::Center]));
+        builder.formats((
+            NumberFormat::None,
+            [NumberFormat::Percent(1), NumberFormat::Binary(0)],
+        ));
+        builder.row(("a".into(), ["0.25".into(), "2048".into()]));
+        let table = builder.finish();
+
+        let json = serde_json::to_string(&table).unwrap();
+        assert_eq!(
+            json,
+            r#"{"header":["name","share","size"],"alignments":["left","decimal","center"],"formats":["none",{"percent":1},{"binary":0}],"style":"github","escape":"reject","rows":[["a","0.25","2048"]]}"#
+        );
+
+        let back: Table<String, String, String, 2> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.to_string(), table.to_string());
+        assert_eq!(serde_json::to_string(&back).unwrap(), json);
+
+        let back: DynTable<String, String, String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.to_string(), table.to_string());
+    }
+
+    #[test]
+    fn optional_fields_may_be_left_out() {
+        let table: Table<String, String, String, 1> =
+            serde_json::from_str(r#"{"header":["a","b"]}"#).unwrap();
+        assert_eq!(table.to_string(), "a   |   b\n--- | --:\n");
+    }
+
+    #[test]
+    fn rows_must_match_width() {
+        let json = r#"{"header":["a","b"],"rows":[["1","2","3"]]}"#;
+        let error = serde_json::from_str::<Table<String, String, String, 1>>(json).unwrap_err();
+        assert!(
+            error.to_string().contains("expected a row of 2 cells"),
+            "{}",
+            error
+        );
+
+        let json = r#"{"header":["a","b"],"rows":[["1"]]}"#;
+        assert!(serde_json::from_str::<DynTable<String, String, String>>(json).is_err());
+    }
+
+    #[test]
+    fn rejected_cells_fail_to_deserialize() {
+        let json = r#"{"header":["a","b"],"escape":"reject","rows":[["x|y","1"]]}"#;
+        assert!(serde_json::from_str::<Table<String, String, String, 1>>(json).is_err());
+    }
+}
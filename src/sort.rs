@@ -0,0 +1,211 @@
+use std::{cmp::Ordering, fmt::Display, iter::Peekable, str::Chars};
+
+use crate::{DynRow, DynTable, Row, RowView, Table};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Order {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl Order {
+    fn apply(self, ordering: Ordering) -> Ordering {
+        match self {
+            Self::Ascending => ordering,
+            Self::Descending => ordering.reverse(),
+        }
+    }
+}
+
+impl<LH, TH, T, const WIDTH: usize> Table<LH, TH, T, WIDTH>
+where
+    LH: Display,
+    TH: Display,
+    T: Display,
+{
+    /// Sorts rows by a column, where column `0` holds the row headers. Cells
+    /// that are numbers compare by value and come before all other cells,
+    /// which compare runs of digits as numbers, so `a9` comes before `a10`.
+    pub fn sort_by_column(&mut self, column: usize, order: Order) {
+        self.sort_by_columns(&[(column, order)])
+    }
+
+    /// Sorts rows by the first column in `keys`, breaking ties with the
+    /// following ones. Rows that compare equal keep their order.
+    pub fn sort_by_columns(&mut self, keys: &[(usize, Order)]) {
+        sort_rows(&mut self.content, WIDTH, Row::view, keys, natural_cmp)
+    }
+
+    /// Sorts rows by a column with `compare` applied to the cells' text.
+    pub fn sort_by_column_with(
+        &mut self,
+        column: usize,
+        compare: impl FnMut(&str, &str) -> Ordering,
+    ) {
+        let keys = [(column, Order::Ascending)];
+        sort_rows(&mut self.content, WIDTH, Row::view, &keys, compare)
+    }
+}
+
+impl<LH, TH, T> DynTable<LH, TH, T>
+where
+    LH: Display,
+    TH: Display,
+    T: Display,
+{
+    pub fn sort_by_column(&mut self, column: usize, order: Order) {
+        self.sort_by_columns(&[(column, order)])
+    }
+
+    pub fn sort_by_columns(&mut self, keys: &[(usize, Order)]) {
+        let width = self.width();
+        sort_rows(&mut self.content, width, DynRow::view, keys, natural_cmp)
+    }
+
+    pub fn sort_by_column_with(
+        &mut self,
+        column: usize,
+        compare: impl FnMut(&str, &str) -> Ordering,
+    ) {
+        let width = self.width();
+        let keys = [(column, Order::Ascending)];
+        sort_rows(&mut self.content, width, DynRow::view, &keys, compare)
+    }
+}
+
+/// Formats the key cells of every row once, then sorts stably.
+fn sort_rows<R, H: Display, C: Display>(
+    rows: &mut Vec<R>,
+    width: usize,
+    view: impl Fn(&R) -> RowView<'_, H, C>,
+    keys: &[(usize, Order)],
+    mut compare: impl FnMut(&str, &str) -> Ordering,
+) {
+    for &(column, _) in keys {
+        check_column(column, width);
+    }
+
+    let mut keyed: Vec<(Vec<String>, R)> = rows
+        .drain(..)
+        .map(|row| {
+            let texts = keys.iter().map(|&(column, _)| view(&row).text(column));
+            (texts.collect(), row)
+        })
+        .collect();
+
+    keyed.sort_by(|(lhs, _), (rhs, _)| {
+        keys.iter()
+            .zip(lhs.iter().zip(rhs))
+            .map(|(&(_, order), (lhs, rhs))| order.apply(compare(lhs, rhs)))
+            .find(|&ordering| ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    rows.extend(keyed.into_iter().map(|(_, row)| row));
+}
+
+pub(crate) fn check_column(column: usize, width: usize) {
+    assert!(
+        column <= width,
+        "column {} is out of range for a table with {} columns",
+        column,
+        width + 1
+    );
+}
+
+/// Numbers by value before text in natural order. Each cell falls on one
+/// side regardless of what it is compared with, so the order stays total.
+fn natural_cmp(lhs: &str, rhs: &str) -> Ordering {
+    match (number(lhs), number(rhs)) {
+        (Some(lhs), Some(rhs)) => lhs.partial_cmp(&rhs).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => text_cmp(lhs, rhs),
+    }
+}
+
+fn number(text: &str) -> Option<f64> {
+    text.trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| !value.is_nan())
+}
+
+fn text_cmp(lhs: &str, rhs: &str) -> Ordering {
+    let (mut lhs_chars, mut rhs_chars) = (lhs.chars().peekable(), rhs.chars().peekable());
+    loop {
+        let ordering = match (lhs_chars.peek(), rhs_chars.peek()) {
+            (None, None) => return lhs.cmp(rhs),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(l), Some(r)) if l.is_ascii_digit() && r.is_ascii_digit() => {
+                compare_digits(&digits(&mut lhs_chars), &digits(&mut rhs_chars))
+            }
+            (Some(&l), Some(&r)) => {
+                lhs_chars.next();
+                rhs_chars.next();
+                l.cmp(&r)
+            }
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+fn digits(chars: &mut Peekable<Chars<'_>>) -> String {
+    let mut digits = String::new();
+    while let Some(&c) = chars.peek().filter(|c| c.is_ascii_digit()) {
+        digits.push(c);
+        chars.next();
+    }
+    digits
+}
+
+fn compare_digits(lhs: &str, rhs: &str) -> Ordering {
+    let lhs = lhs.trim_start_matches('0');
+    let rhs = rhs.trim_start_matches('0');
+    lhs.len().cmp(&rhs.len()).then_with(|| lhs.cmp(rhs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{natural_cmp, Order};
+    use crate::{DynBuilder, DynTable};
+
+    #[test]
+    fn natural_order_ignores_input_order() {
+        let orders = [
+            ["1.5", "1.7a", "1.10"],
+            ["1.10", "1.7a", "1.5"],
+            ["1.7a", "1.5", "1.10"],
+            ["1.7a", "1.10", "1.5"],
+        ];
+        for mut cells in orders {
+            cells.sort_by(|lhs, rhs| natural_cmp(lhs, rhs));
+            assert_eq!(cells, ["1.10", "1.5", "1.7a"]);
+        }
+    }
+
+    #[test]
+    fn natural_order_compares_digit_runs_in_text() {
+        let mut cells = ["a10", "b", "a9", "10", "a09x", "9"];
+        cells.sort_by(|lhs, rhs| natural_cmp(lhs, rhs));
+        assert_eq!(cells, ["9", "10", "a9", "a09x", "a10", "b"]);
+    }
+
+    #[test]
+    fn sort_by_column_descending() {
+        let mut builder = DynBuilder::new();
+        builder.header(("name", vec!["size"]));
+        for (name, size) in [("a", "2"), ("b", "10"), ("c", "n/a")] {
+            builder.row((name, vec![size]));
+        }
+        let mut table: DynTable<&str, &str, &str> = builder.finish();
+
+        table.sort_by_column(1, Order::Descending);
+        let names: Vec<&str> = table.content.iter().map(|row| *row.header()).collect();
+        assert_eq!(names, ["c", "b", "a"]);
+    }
+}
@@ -0,0 +1,155 @@
+use std::{fmt::Display, iter, ops::Index};
+
+use crate::{Alignment, Decimals, EscapePolicy, Format, NumberFormat};
+
+/// Borrowed parts of a `Table` or `DynTable`, so every output format is
+/// written once regardless of how the table stores its rows.
+#[derive(Debug)]
+pub(crate) struct View<'t, LH, TH, T> {
+    pub(crate) header: RowView<'t, LH, TH>,
+    pub(crate) alignments: RowView<'t, Alignment, Alignment>,
+    /// Width of every column, starting with the row header column, widened
+    /// to fit decimal alignment.
+    pub(crate) widths: Vec<usize>,
+    pub(crate) rows: Vec<RowView<'t, LH, T>>,
+    pub(crate) formats: Option<RowView<'t, NumberFormat, NumberFormat>>,
+    /// Layout of every `Alignment::Decimal` column once its cells are
+    /// escaped for markdown.
+    pub(crate) decimals: Vec<Option<Decimals>>,
+    /// Layout of every `Alignment::Decimal` column for renderers that write
+    /// cells unescaped.
+    pub(crate) raw_decimals: Vec<Option<Decimals>>,
+    pub(crate) format: Format,
+}
+
+#[derive(Debug)]
+pub(crate) struct RowView<'t, H, C> {
+    pub(crate) header: &'t H,
+    pub(crate) content: &'t [C],
+}
+
+impl<'t, H, C> RowView<'t, H, C> {
+    pub(crate) fn new(header: &'t H, content: &'t [C]) -> Self {
+        Self { header, content }
+    }
+}
+
+impl<H, C> Clone for RowView<'_, H, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<H, C> Copy for RowView<'_, H, C> {}
+
+impl<'t, H: Display, C: Display> RowView<'t, H, C> {
+    /// Every cell of the row, starting with the row header.
+    pub(crate) fn texts(self) -> impl Iterator<Item = String> + 't {
+        iter::once(self.header.to_string()).chain(self.content.iter().map(ToString::to_string))
+    }
+
+    /// The cell in `column`, where column `0` holds the row header.
+    pub(crate) fn text(self, column: usize) -> String {
+        match column {
+            0 => self.header.to_string(),
+            i => self.content[i - 1].to_string(),
+        }
+    }
+
+    /// Like `texts`, with each column's number format applied.
+    pub(crate) fn formatted(
+        self,
+        formats: Option<RowView<'t, NumberFormat, NumberFormat>>,
+    ) -> impl Iterator<Item = String> + 't {
+        self.texts().enumerate().map(move |(i, text)| {
+            let format = formats.and_then(|formats| match i {
+                0 => Some(formats.header),
+                i => formats.content.get(i - 1),
+            });
+            match format {
+                Some(format) => format.apply(text),
+                None => text,
+            }
+        })
+    }
+}
+
+impl<T> Index<usize> for RowView<'_, T, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => self.header,
+            x => &self.content[x - 1],
+        }
+    }
+}
+
+impl<'t, LH: Display, TH, T: Display> View<'t, LH, TH, T> {
+    pub(crate) fn new(
+        header: RowView<'t, LH, TH>,
+        alignments: RowView<'t, Alignment, Alignment>,
+        widths: RowView<'t, usize, usize>,
+        rows: Vec<RowView<'t, LH, T>>,
+        formats: Option<RowView<'t, NumberFormat, NumberFormat>>,
+        format: Format,
+    ) -> Self {
+        let measure = |escape: Option<EscapePolicy>| {
+            let mut decimals: Vec<Option<Decimals>> = iter::once(alignments.header)
+                .chain(alignments.content)
+                .map(|&align| (align == Alignment::Decimal).then(Decimals::default))
+                .collect();
+            if decimals.iter().any(Option::is_some) {
+                for row in &rows {
+                    for (decimals, text) in decimals.iter_mut().zip(row.formatted(formats)) {
+                        if let Some(decimals) = decimals {
+                            let text = match escape {
+                                Some(escape) => escape.apply(&text).into_owned(),
+                                None => text,
+                            };
+                            *decimals = Decimals::max(*decimals, Decimals::measure(&text));
+                        }
+                    }
+                }
+            }
+            decimals
+        };
+        let decimals = measure(Some(format.escape));
+        let raw_decimals = measure(None);
+
+        let widths = iter::once(widths.header)
+            .chain(widths.content)
+            .zip(&decimals)
+            .map(|(&width, decimals)| decimals.map_or(width, |d| width.max(d.width())))
+            .collect();
+
+        Self {
+            header,
+            alignments,
+            widths,
+            rows,
+            formats,
+            decimals,
+            raw_decimals,
+            format,
+        }
+    }
+
+    /// Formatted, unescaped cells of a body row, with decimal points lined up
+    /// in `Alignment::Decimal` columns.
+    pub(crate) fn padded(&self, row: RowView<'t, LH, T>) -> impl Iterator<Item = String> + '_ {
+        row.formatted(self.formats)
+            .zip(&self.raw_decimals)
+            .map(|(text, decimals)| match decimals {
+                Some(decimals) => decimals.align(&text),
+                None => text,
+            })
+    }
+}
+
+impl<'t, LH, TH, T> View<'t, LH, TH, T> {
+    /// Alignment of every column, starting with the row header column.
+    pub(crate) fn column_alignments(&self) -> impl Iterator<Item = Alignment> + 't {
+        iter::once(*self.alignments.header).chain(self.alignments.content.iter().copied())
+    }
+}
@@ -0,0 +1,97 @@
+use mdtable::{Alignment, Builder, MdRow, Row};
+
+#[derive(MdRow)]
+struct Package {
+    #[mdtable(rename = "Package", align = "left")]
+    name: &'static str,
+    #[mdtable(align = "decimal", format = "kib")]
+    size: u64,
+    #[mdtable(skip)]
+    #[allow(dead_code)]
+    checksum: u32,
+    #[mdtable(align = "center")]
+    stars: u32,
+}
+
+#[derive(MdRow)]
+struct Score {
+    points: u32,
+    #[mdtable(header)]
+    player: String,
+}
+
+fn kib(bytes: &u64) -> String {
+    format!("{:.1}", *bytes as f64 / 1024.0)
+}
+
+#[test]
+fn header_uses_field_names_and_renames() {
+    let header = <Package as MdRow<2>>::header();
+    assert_eq!(header.header(), "Package");
+    assert_eq!(header.content(), &["size", "stars"]);
+}
+
+#[test]
+fn alignments_default_to_right() {
+    let alignments = <Package as MdRow<2>>::alignments();
+    assert_eq!(*alignments.header(), Alignment::Left);
+    assert_eq!(
+        alignments.content(),
+        &[Alignment::Decimal, Alignment::Center]
+    );
+
+    let alignments = <Score as MdRow<1>>::alignments();
+    assert_eq!(*alignments.header(), Alignment::None);
+    assert_eq!(alignments.content(), &[Alignment::Right]);
+}
+
+#[test]
+fn rows_skip_fields_and_apply_formats() {
+    let row: Row<String, String, 2> = Package {
+        name: "mdtable",
+        size: 3584,
+        checksum: 0xdead,
+        stars: 7,
+    }
+    .into();
+    assert_eq!(row.header(), "mdtable");
+    assert_eq!(row.content(), &["3.5", "7"]);
+}
+
+#[test]
+fn header_field_moves_to_the_front() {
+    let header = <Score as MdRow<1>>::header();
+    assert_eq!(header.header(), "player");
+    assert_eq!(header.content(), &["points"]);
+
+    let row: Row<String, String, 1> = Score {
+        points: 42,
+        player: "ada".into(),
+    }
+    .into();
+    assert_eq!(row.header(), "ada");
+    assert_eq!(row.content(), &["42"]);
+}
+
+#[test]
+fn builds_a_table() {
+    let mut builder = Builder::new();
+    builder.header(<Score as MdRow<1>>::header());
+    builder.alignments(<Score as MdRow<1>>::alignments());
+    builder.row(Score {
+        points: 7,
+        player: "bo".into(),
+    });
+    builder.row(Score {
+        points: 1200,
+        player: "ada".into(),
+    });
+
+    assert_eq!(
+        builder.finish().to_string(),
+        "player | points\n\
+         ------ | -----:\n\
+         bo     |      7\n\
+         ada    |   1200\n"
+    );
+}